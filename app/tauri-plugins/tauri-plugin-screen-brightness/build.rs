@@ -1,4 +1,10 @@
-const COMMANDS: &[&str] = &["set_brightness", "get_brightness"];
+const COMMANDS: &[&str] = &[
+    "set_brightness",
+    "get_brightness",
+    "is_supported",
+    "set_presentation_brightness",
+    "set_brightness_timed",
+];
 
 fn main() {
     tauri_plugin::Builder::new(COMMANDS)
@@ -5,14 +5,56 @@ struct SetBrightnessArgs: Decodable {
     let value: Float
 }
 
+struct SetBrightnessTimedArgs: Decodable {
+    let value: Float
+    let durationMs: Int
+}
+
 class ScreenBrightnessPlugin: Plugin {
+    // Pending restore of a timed brightness override and the level it restores
+    // (only touched on the main thread)
+    private var restoreWorkItem: DispatchWorkItem?
+    private var restoreBrightness: CGFloat?
+
+    private func cancelRestore() {
+        restoreWorkItem?.cancel()
+        restoreWorkItem = nil
+        restoreBrightness = nil
+    }
+
     @objc public func setBrightness(_ invoke: Invoke) throws {
         let args = try invoke.parseArgs(SetBrightnessArgs.self)
         let brightness = max(0.0, min(1.0, CGFloat(args.value)))
 
         // Must be called on main thread
         DispatchQueue.main.async {
+            self.cancelRestore()
+            UIScreen.main.brightness = brightness
+            invoke.resolve()
+        }
+    }
+
+    @objc public func setBrightnessTimed(_ invoke: Invoke) throws {
+        let args = try invoke.parseArgs(SetBrightnessTimedArgs.self)
+        let brightness = max(0.0, min(1.0, CGFloat(args.value)))
+
+        DispatchQueue.main.async {
+            // Back-to-back overrides restore the level from before the first one
+            let previous = self.restoreBrightness ?? UIScreen.main.brightness
+            self.restoreWorkItem?.cancel()
+
+            let workItem = DispatchWorkItem {
+                UIScreen.main.brightness = previous
+                self.restoreWorkItem = nil
+                self.restoreBrightness = nil
+            }
+            self.restoreWorkItem = workItem
+            self.restoreBrightness = previous
             UIScreen.main.brightness = brightness
+            DispatchQueue.main.asyncAfter(
+                deadline: .now() + .milliseconds(max(0, args.durationMs)),
+                execute: workItem
+            )
             invoke.resolve()
         }
     }
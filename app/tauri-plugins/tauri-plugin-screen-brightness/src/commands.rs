@@ -1,17 +1,57 @@
-use tauri::{AppHandle, Runtime};
+use tauri::{AppHandle, Manager, Runtime};
 
-use crate::ScreenBrightnessExt;
+use crate::{PresentationBrightness, ScreenBrightnessExt};
 
 #[tauri::command]
 pub async fn set_brightness<R: Runtime>(app: AppHandle<R>, value: f32) -> Result<(), String> {
+    // A presentation fade still running would overwrite the manual change
+    app.state::<PresentationBrightness>().cancel_transition();
     app.screen_brightness()
         .set_brightness(value)
         .map_err(|e| e.to_string())
 }
 
+/// Sets brightness for `duration_ms`, then restores the previous level (e.g. a 30 second
+/// "spotlight"). The timer runs natively so bridge latency can't delay the restore; any
+/// later brightness change cancels it.
+#[tauri::command]
+pub async fn set_brightness_timed<R: Runtime>(
+    app: AppHandle<R>,
+    value: f32,
+    duration_ms: u64,
+) -> Result<(), String> {
+    // A presentation fade still running would fight the override
+    app.state::<PresentationBrightness>().cancel_transition();
+    app.screen_brightness()
+        .set_brightness_timed(value, duration_ms)
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn is_supported<R: Runtime>(app: AppHandle<R>) -> Result<bool, String> {
+    app.screen_brightness()
+        .is_supported()
+        .map_err(|e| e.to_string())
+}
+
 #[tauri::command]
 pub async fn get_brightness<R: Runtime>(app: AppHandle<R>) -> Result<f32, String> {
     app.screen_brightness()
         .get_brightness()
         .map_err(|e| e.to_string())
 }
+
+/// Goes to full brightness while a slide is showing and restores the previous level on idle
+#[tauri::command]
+pub async fn set_presentation_brightness<R: Runtime>(
+    app: AppHandle<R>,
+    active: bool,
+) -> Result<(), String> {
+    tauri::async_runtime::spawn_blocking(move || {
+        app.state::<PresentationBrightness>()
+            .set_active(&app, active)
+            .map_err(|e| e.to_string())
+    })
+    .await
+    .map_err(|e| e.to_string())?
+}
@@ -17,8 +17,18 @@ impl ScreenBrightness {
         Ok(())
     }
 
+    pub fn set_brightness_timed(&self, _value: f32, _duration_ms: u64) -> crate::Result<()> {
+        // No-op on desktop
+        Ok(())
+    }
+
     pub fn get_brightness(&self) -> crate::Result<f32> {
         // Default to full brightness on desktop
         Ok(1.0)
     }
+
+    pub fn is_supported(&self) -> crate::Result<bool> {
+        // Setting brightness is a no-op on desktop
+        Ok(false)
+    }
 }
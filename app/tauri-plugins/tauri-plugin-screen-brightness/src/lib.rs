@@ -10,8 +10,10 @@ mod mobile;
 
 mod commands;
 mod error;
+mod presentation;
 
 pub use error::{Error, Result};
+pub use presentation::PresentationBrightness;
 
 #[cfg(desktop)]
 pub use desktop::ScreenBrightness;
@@ -48,7 +50,10 @@ pub fn init<R: Runtime>() -> TauriPlugin<R> {
     Builder::new("screen-brightness")
         .invoke_handler(tauri::generate_handler![
             commands::set_brightness,
-            commands::get_brightness
+            commands::get_brightness,
+            commands::is_supported,
+            commands::set_presentation_brightness,
+            commands::set_brightness_timed
         ])
         .setup(|app, api| {
             #[cfg(mobile)]
@@ -56,6 +61,7 @@ pub fn init<R: Runtime>() -> TauriPlugin<R> {
             #[cfg(desktop)]
             let screen_brightness = desktop::init(app, api)?;
             app.manage(screen_brightness);
+            app.manage(PresentationBrightness::default());
             Ok(())
         })
         .build()
@@ -33,6 +33,16 @@ impl<R: Runtime> ScreenBrightness<R> {
             .map_err(|e| crate::Error::Plugin(e.to_string()))
     }
 
+    pub fn set_brightness_timed(&self, value: f32, duration_ms: u64) -> crate::Result<()> {
+        let clamped = value.clamp(0.0, 1.0);
+        self.0
+            .run_mobile_plugin(
+                "setBrightnessTimed",
+                serde_json::json!({ "value": clamped, "durationMs": duration_ms }),
+            )
+            .map_err(|e| crate::Error::Plugin(e.to_string()))
+    }
+
     pub fn get_brightness(&self) -> crate::Result<f32> {
         let result: serde_json::Value = self
             .0
@@ -41,4 +51,9 @@ impl<R: Runtime> ScreenBrightness<R> {
 
         Ok(result["brightness"].as_f64().unwrap_or(1.0) as f32)
     }
+
+    pub fn is_supported(&self) -> crate::Result<bool> {
+        // Only the iOS plugin is implemented; Android fails at init
+        Ok(cfg!(target_os = "ios"))
+    }
 }
@@ -0,0 +1,101 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::Duration;
+
+use tauri::{AppHandle, Runtime};
+
+use crate::ScreenBrightnessExt;
+
+/// Brightness used while a slide is showing
+const PRESENTATION_BRIGHTNESS: f32 = 1.0;
+/// Number of steps in a brightness transition
+const TRANSITION_STEPS: u32 = 10;
+/// Delay between transition steps (300ms total)
+const TRANSITION_STEP_DELAY: Duration = Duration::from_millis(30);
+
+/// Brightness levels remembered across presentation changes
+#[derive(Default)]
+struct Levels {
+    /// Brightness before the presentation became active, if it is active
+    idle: Option<f32>,
+    /// Level a restore transition still in progress is heading to
+    restoring: Option<f32>,
+}
+
+/// Tracks the brightness to restore once the presentation goes idle
+#[derive(Default)]
+pub struct PresentationBrightness {
+    levels: Mutex<Levels>,
+    /// Bumped on every change so an older transition stops when a newer one starts
+    generation: AtomicU64,
+}
+
+impl PresentationBrightness {
+    /// Raises brightness to max while presenting and restores the previous level on idle
+    pub fn set_active<R: Runtime>(&self, app: &AppHandle<R>, active: bool) -> crate::Result<()> {
+        let target = {
+            let mut levels = self.levels.lock().unwrap();
+            if active {
+                if levels.idle.is_none() {
+                    // Reactivating mid-restore: the restore target is the idle level, not
+                    // the half-restored brightness on screen
+                    levels.idle = match levels.restoring.take() {
+                        Some(brightness) => Some(brightness),
+                        None => Some(app.screen_brightness().get_brightness()?),
+                    };
+                }
+                PRESENTATION_BRIGHTNESS
+            } else {
+                match levels.idle.take() {
+                    Some(brightness) => {
+                        levels.restoring = Some(brightness);
+                        brightness
+                    }
+                    // Never went active, nothing to restore
+                    None => return Ok(()),
+                }
+            }
+        };
+
+        let generation = self.generation.fetch_add(1, Ordering::SeqCst) + 1;
+        let result = self.transition(app, target, generation);
+        if !active {
+            let mut levels = self.levels.lock().unwrap();
+            // Unless a newer change superseded it, the restore is over
+            if self.generation.load(Ordering::SeqCst) == generation {
+                levels.restoring = None;
+            }
+        }
+        result
+    }
+
+    /// Stops a transition in progress, leaving brightness where it is (e.g. for a manual
+    /// brightness change, which then becomes the level to restore after presenting)
+    pub fn cancel_transition(&self) {
+        let mut levels = self.levels.lock().unwrap();
+        self.generation.fetch_add(1, Ordering::SeqCst);
+        levels.restoring = None;
+    }
+
+    /// Steps brightness towards the target, stopping early if superseded
+    fn transition<R: Runtime>(
+        &self,
+        app: &AppHandle<R>,
+        target: f32,
+        generation: u64,
+    ) -> crate::Result<()> {
+        let start = app.screen_brightness().get_brightness()?;
+        for step in 1..=TRANSITION_STEPS {
+            if self.generation.load(Ordering::SeqCst) != generation {
+                return Ok(());
+            }
+            let progress = step as f32 / TRANSITION_STEPS as f32;
+            app.screen_brightness()
+                .set_brightness(start + (target - start) * progress)?;
+            if step < TRANSITION_STEPS {
+                std::thread::sleep(TRANSITION_STEP_DELAY);
+            }
+        }
+        Ok(())
+    }
+}
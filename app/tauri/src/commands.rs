@@ -1,11 +1,84 @@
 use crate::domain::{AppState, ServerConfig};
+#[cfg(desktop)]
+use crate::domain::{ExitState, ServerPing, ServerResourceUsage};
+use crate::error::{ExternalUrlError, ImportFileError};
+use log::{info, warn};
 use parking_lot::Mutex;
-use std::path::PathBuf;
+use serde::Serialize;
+#[cfg(desktop)]
+use std::collections::BTreeMap;
+use std::collections::VecDeque;
+use std::fs::File;
+use std::io::{Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+#[cfg(desktop)]
+use std::sync::atomic::Ordering;
+#[cfg(desktop)]
+use std::time::Duration;
+#[cfg(desktop)]
+use tauri::{Emitter, Manager};
 
+/// File extensions handled through file association
+pub const IMPORTABLE_EXTENSIONS: &[&str] = &["pptx", "opensong", "churchprogram"];
 
-/// State for storing pending PPTX file import from file association
+/// State for storing pending file imports from file association (oldest first)
 pub struct PendingImport {
-    pub file_path: Mutex<Option<PathBuf>>,
+    pub file_paths: Mutex<VecDeque<PathBuf>>,
+}
+
+/// Checks if a path has one of the extensions we can import
+pub fn is_importable_file(path: &Path) -> bool {
+    path.extension().is_some_and(|ext| {
+        IMPORTABLE_EXTENSIONS
+            .iter()
+            .any(|supported| ext.eq_ignore_ascii_case(supported))
+    })
+}
+
+/// Encodes an import path for the frontend so it survives the round-trip to the sidecar.
+/// UTF-8 paths (spaces, accents, `#` included) are passed through unchanged; paths that
+/// aren't valid UTF-8 are sent as a percent-encoded `file://` URL instead of being mangled.
+pub fn encode_import_path(path: &Path) -> String {
+    if let Some(path) = path.to_str() {
+        return path.to_string();
+    }
+    match tauri::Url::from_file_path(path) {
+        Ok(url) => url.to_string(),
+        Err(()) => path.to_string_lossy().to_string(),
+    }
+}
+
+/// Decodes a path produced by `encode_import_path`
+pub fn decode_import_path(encoded: &str) -> PathBuf {
+    tauri::Url::parse(encoded)
+        .ok()
+        .filter(|url| url.scheme() == "file")
+        .and_then(|url| url.to_file_path().ok())
+        .unwrap_or_else(|| PathBuf::from(encoded))
+}
+
+/// Queues a file for import in the running instance, notifies the frontend
+/// and brings the main window to front
+#[cfg(desktop)]
+pub fn forward_file_import(app: &tauri::AppHandle, path: PathBuf) {
+    info!(target: "file-association", "Forwarding file to running instance: {path:?}");
+
+    if let Some(pending) = app.try_state::<PendingImport>() {
+        pending.file_paths.lock().push_back(path.clone());
+    }
+
+    // Emit event to frontend so it can import the file
+    if let Err(e) = app.emit("file-opened", encode_import_path(&path)) {
+        warn!(target: "file-association", "Failed to emit file-opened: {e}");
+    }
+
+    // Focus the main window
+    if let Some(window) = app.get_webview_window("main") {
+        // Unminimize if minimized
+        let _ = window.unminimize();
+        // Bring to front and focus
+        let _ = window.set_focus();
+    }
 }
 
 /// State for storing current zoom level per webview
@@ -18,24 +91,339 @@ pub struct ZoomState {
 pub fn get_server_config(app_state: tauri::State<AppState>) -> Result<ServerConfig, String> {
     let server_config = ServerConfig {
         server_port: app_state.server_port,
+        server_error: app_state.server_error.lock().clone(),
     };
     Ok(server_config)
 }
 
-/// Gets the pending import file path if one exists (from file association)
+/// Takes the next pending import file path if one exists (from file association)
 #[tauri::command]
 pub fn get_pending_import(state: tauri::State<PendingImport>) -> Option<String> {
     state
-        .file_path
+        .file_paths
         .lock()
-        .take()
-        .map(|p| p.to_string_lossy().to_string())
+        .pop_front()
+        .map(|p| encode_import_path(&p))
 }
 
-/// Clears the pending import (called after import is handled)
+/// Clears all pending imports (called after import is handled)
 #[tauri::command]
 pub fn clear_pending_import(state: tauri::State<PendingImport>) {
-    *state.file_path.lock() = None;
+    state.file_paths.lock().clear();
+}
+
+/// Bytes every zip archive (pptx, churchprogram) starts with
+const ZIP_MAGIC: &[u8] = b"PK\x03\x04";
+
+/// How much of the end of a pptx is searched for its entry names (the zip central directory)
+const ZIP_TAIL_BYTES: u64 = 64 * 1024;
+
+/// An import file that passed validation
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportFileInfo {
+    pub path: String,
+    /// Lowercase extension, e.g. `pptx`
+    pub format: String,
+    pub size_bytes: u64,
+}
+
+/// Checks a file's contents match its format. Returns a reason when they don't,
+/// or the I/O error if the file couldn't be read.
+fn check_import_contents(
+    file: &mut File,
+    format: &str,
+    size: u64,
+) -> std::io::Result<Result<(), &'static str>> {
+    if size == 0 {
+        return Ok(Err("the file is empty"));
+    }
+
+    let mut head = Vec::with_capacity(512);
+    file.by_ref().take(512).read_to_end(&mut head)?;
+
+    match format {
+        "pptx" | "churchprogram" => {
+            if !head.starts_with(ZIP_MAGIC) {
+                return Ok(Err("it is not a zip archive"));
+            }
+            if format == "pptx" {
+                let mut tail = Vec::new();
+                file.seek(SeekFrom::Start(size.saturating_sub(ZIP_TAIL_BYTES)))?;
+                file.read_to_end(&mut tail)?;
+                if !tail.windows(4).any(|window| window == b"ppt/") {
+                    return Ok(Err("the archive contains no presentation"));
+                }
+            }
+        }
+        "opensong" => {
+            let text = head.strip_prefix(b"\xEF\xBB\xBF").unwrap_or(&head);
+            if text.trim_ascii_start().first() != Some(&b'<') {
+                return Ok(Err("it is not an XML document"));
+            }
+        }
+        _ => {}
+    }
+    Ok(Ok(()))
+}
+
+/// Checks an import file exists, is readable and looks like the format its extension
+/// claims (zip for pptx/churchprogram, XML for opensong)
+pub fn validate_import_path(path: &Path) -> Result<ImportFileInfo, ImportFileError> {
+    let display_path = path.display().to_string();
+    let format = path
+        .extension()
+        .map(|ext| ext.to_string_lossy().to_lowercase())
+        .unwrap_or_default();
+    if !is_importable_file(path) {
+        return Err(ImportFileError::UnsupportedFormat { extension: format });
+    }
+    if !path.is_file() {
+        return Err(ImportFileError::NotFound { path: display_path });
+    }
+
+    let unreadable = |e: std::io::Error| ImportFileError::Unreadable {
+        path: display_path.clone(),
+        reason: e.to_string(),
+    };
+    let mut file = File::open(path).map_err(unreadable)?;
+    let size = file.metadata().map_err(unreadable)?.len();
+
+    if let Err(reason) = check_import_contents(&mut file, &format, size).map_err(unreadable)? {
+        return Err(ImportFileError::Corrupt {
+            path: display_path,
+            format,
+            reason: reason.to_string(),
+        });
+    }
+
+    Ok(ImportFileInfo {
+        path: encode_import_path(path),
+        format,
+        size_bytes: size,
+    })
+}
+
+/// Validates a file before import so a corrupt or unsupported file gets a clear error
+/// instead of failing deep in the import pipeline
+/// Accepts plain paths as well as the `file://` URLs sent for non-UTF8 import paths
+/// Relative paths are resolved against the media root
+#[tauri::command]
+pub async fn validate_import_file(
+    app: tauri::AppHandle,
+    path: String,
+) -> Result<ImportFileInfo, ImportFileError> {
+    let path = resolve_media_path(&app, decode_import_path(&path));
+    tokio::task::spawn_blocking(move || validate_import_path(&path))
+        .await
+        .map_err(|e| ImportFileError::Unreadable {
+            path: String::new(),
+            reason: e.to_string(),
+        })?
+}
+
+/// Shows a file selected in Finder/Explorer/the Linux file manager
+/// Accepts plain paths as well as the `file://` URLs sent for non-UTF8 import paths
+#[cfg(desktop)]
+#[tauri::command]
+pub fn reveal_in_file_manager(app: tauri::AppHandle, path: String) -> Result<(), String> {
+    use tauri_plugin_opener::OpenerExt;
+
+    let path = decode_import_path(&path);
+    if !path.exists() {
+        return Err(format!("File not found: {}", path.display()));
+    }
+
+    info!(target: "file-manager", "Revealing {path:?}");
+    app.opener()
+        .reveal_item_in_dir(&path)
+        .map_err(|e| format!("Failed to reveal file: {e}"))
+}
+
+/// URL schemes that may be opened in the system browser
+const EXTERNAL_URL_SCHEMES: &[&str] = &["http", "https", "mailto"];
+
+/// Opens a URL in the system browser, rejecting anything but http/https/mailto
+/// so embedded content can't open `file://` or custom-scheme URLs
+#[tauri::command]
+pub fn open_external_url(app: tauri::AppHandle, url: String) -> Result<(), ExternalUrlError> {
+    use tauri_plugin_opener::OpenerExt;
+
+    let parsed = url
+        .parse::<tauri::Url>()
+        .map_err(|e| ExternalUrlError::InvalidUrl {
+            url: url.clone(),
+            reason: e.to_string(),
+        })?;
+    if !EXTERNAL_URL_SCHEMES.contains(&parsed.scheme()) {
+        info!(target: "opener", "Rejected URL with scheme '{}'", parsed.scheme());
+        return Err(ExternalUrlError::SchemeNotAllowed {
+            scheme: parsed.scheme().to_string(),
+        });
+    }
+
+    app.opener()
+        .open_url(parsed.as_str(), None::<&str>)
+        .map_err(|e| ExternalUrlError::PlatformError(format!("Failed to open URL: {e}")))
+}
+
+/// Store key for the last route the frontend chose to persist
+pub const LAST_ROUTE_KEY: &str = "lastRoute";
+
+/// Persists the frontend route to restore on next launch
+#[tauri::command]
+pub fn save_last_route(app: tauri::AppHandle, route: String) -> Result<(), String> {
+    crate::store::set_value(&app, LAST_ROUTE_KEY, &route)
+}
+
+/// Gets the last persisted route (None if never saved or the store is unreadable)
+#[tauri::command]
+pub fn get_last_route(app: tauri::AppHandle) -> Option<String> {
+    crate::store::get_value(&app, LAST_ROUTE_KEY)
+}
+
+/// Store key for the default media folder
+pub const MEDIA_ROOT_KEY: &str = "mediaRoot";
+
+/// Gets the media root if it is set and still a directory
+pub fn media_root(app: &tauri::AppHandle) -> Option<PathBuf> {
+    crate::store::get_value::<String>(app, MEDIA_ROOT_KEY)
+        .map(|root| decode_import_path(&root))
+        .filter(|root| root.is_dir())
+}
+
+/// Resolves a relative path against the media root (absolute paths are kept as is)
+pub fn resolve_media_path(app: &tauri::AppHandle, path: PathBuf) -> PathBuf {
+    if path.is_absolute() {
+        return path;
+    }
+    match media_root(app) {
+        Some(root) => root.join(path),
+        None => path,
+    }
+}
+
+/// Sets the default media folder used as the starting directory of file dialogs and
+/// the base for relative import paths, or clears it when `path` is None.
+/// Returns the stored path.
+#[tauri::command]
+pub fn set_media_root(
+    app: tauri::AppHandle,
+    path: Option<String>,
+) -> Result<Option<String>, String> {
+    let Some(path) = path else {
+        info!(target: "media", "Clearing media root");
+        crate::store::remove_value(&app, MEDIA_ROOT_KEY)?;
+        return Ok(None);
+    };
+
+    let path = decode_import_path(&path);
+    if !path.is_absolute() {
+        return Err(format!(
+            "Media root must be an absolute path: {}",
+            path.display()
+        ));
+    }
+    if !path.is_dir() {
+        return Err(format!("Directory not found: {}", path.display()));
+    }
+
+    info!(target: "media", "Setting media root to {path:?}");
+    let encoded = encode_import_path(&path);
+    crate::store::set_value(&app, MEDIA_ROOT_KEY, &encoded)?;
+    Ok(Some(encoded))
+}
+
+/// Gets the default media folder (None if never set or no longer a directory)
+#[tauri::command]
+pub fn get_media_root(app: tauri::AppHandle) -> Option<String> {
+    media_root(&app).map(|root| encode_import_path(&root))
+}
+
+/// Total and free space of a disk, in bytes
+#[cfg(desktop)]
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DiskSpace {
+    pub mount_point: String,
+    pub total_bytes: u64,
+    pub free_bytes: u64,
+}
+
+/// Finds the disk holding a path (which may not exist yet, e.g. a recording about to start)
+#[cfg(desktop)]
+fn disk_space_at(path: &Path) -> Result<DiskSpace, String> {
+    let existing = path
+        .ancestors()
+        .find(|ancestor| ancestor.exists())
+        .ok_or_else(|| format!("Path not found: {}", path.display()))?;
+    let path = existing
+        .canonicalize()
+        .map_err(|e| format!("Failed to resolve path: {e}"))?;
+
+    // The disk with the longest mount point containing the path
+    let disks = sysinfo::Disks::new_with_refreshed_list();
+    disks
+        .list()
+        .iter()
+        .filter(|disk| path.starts_with(disk.mount_point()))
+        .max_by_key(|disk| disk.mount_point().as_os_str().len())
+        .map(|disk| DiskSpace {
+            mount_point: disk.mount_point().to_string_lossy().to_string(),
+            total_bytes: disk.total_space(),
+            free_bytes: disk.available_space(),
+        })
+        .ok_or_else(|| format!("No disk found for {}", path.display()))
+}
+
+/// Gets the total and free space of the disk holding `path` (relative paths resolve
+/// against the media root), or of the media root when no path is given, so the frontend
+/// can warn before a long recording or large import fills the disk. Without a media root
+/// the app data directory is used.
+#[cfg(desktop)]
+#[tauri::command]
+pub async fn get_disk_space(
+    app: tauri::AppHandle,
+    path: Option<String>,
+) -> Result<DiskSpace, String> {
+    let path = match path {
+        Some(path) => resolve_media_path(&app, decode_import_path(&path)),
+        None => match media_root(&app) {
+            Some(root) => root,
+            None => app
+                .path()
+                .app_data_dir()
+                .map_err(|e| format!("Failed to resolve app data directory: {e}"))?,
+        },
+    };
+    tokio::task::spawn_blocking(move || disk_space_at(&path))
+        .await
+        .map_err(|e| format!("Failed to query disk space: {e}"))?
+}
+
+/// Gets the stored sidecar environment overrides
+#[cfg(desktop)]
+#[tauri::command]
+pub fn get_sidecar_environment(app: tauri::AppHandle) -> BTreeMap<String, String> {
+    crate::store::get_value(&app, crate::server::SIDECAR_ENVIRONMENT_KEY).unwrap_or_default()
+}
+
+/// Stores sidecar environment overrides (e.g. `NODE_ENV=staging`), applied from the next
+/// server start (`CHURCH_HUB_SIDECAR_ENV` still takes precedence)
+#[cfg(desktop)]
+#[tauri::command]
+pub fn set_sidecar_environment(
+    app: tauri::AppHandle,
+    environment: BTreeMap<String, String>,
+) -> Result<(), String> {
+    for name in environment.keys() {
+        crate::server::validate_sidecar_variable(name)?;
+    }
+    info!(
+        target: "sidecar",
+        "Setting environment overrides {environment:?} (applies on next server start)"
+    );
+    crate::store::set_value(&app, crate::server::SIDECAR_ENVIRONMENT_KEY, &environment)
 }
 
 /// Toggle DevTools for the calling webview
@@ -49,6 +437,17 @@ pub fn toggle_devtools(webview: tauri::Webview) {
     }
 }
 
+/// Checks if DevTools are open for a webview (defaults to the main window).
+/// Queries the platform webview, so DevTools opened or closed from its own UI are reflected.
+#[cfg(desktop)]
+#[tauri::command]
+pub fn is_devtools_open(app: tauri::AppHandle, label: Option<String>) -> Result<bool, String> {
+    let label = label.as_deref().unwrap_or(crate::window::MAIN_WINDOW_LABEL);
+    app.get_webview(label)
+        .map(|webview| webview.is_devtools_open())
+        .ok_or_else(|| format!("Webview '{label}' not found"))
+}
+
 /// Zoom in the calling webview
 #[cfg(desktop)]
 #[tauri::command]
@@ -107,9 +506,169 @@ pub fn reset_zoom(
     Ok(1.0)
 }
 
+/// Acknowledges `before-exit` once the frontend has saved, letting the app quit
+#[cfg(desktop)]
+#[tauri::command]
+pub fn acknowledge_exit(exit_state: tauri::State<ExitState>) {
+    if exit_state.exiting.load(Ordering::SeqCst) {
+        info!(target: "window-event", "Frontend acknowledged before-exit");
+        exit_state.acknowledged.notify_one();
+    }
+}
+
+/// Blocks or allows closing the main window. While enabled, close requests (e.g. an
+/// accidental Alt+F4 mid-service) are refused and emitted as `close-blocked` so the UI
+/// can ask for confirmation and call `force_close`.
+#[cfg(desktop)]
+#[tauri::command]
+pub fn set_close_protection(exit_state: tauri::State<ExitState>, enabled: bool) {
+    info!(target: "window-event", "Setting close protection to {enabled}");
+    exit_state.close_protected.store(enabled, Ordering::SeqCst);
+}
+
+/// Checks if closing the main window is blocked
+#[cfg(desktop)]
+#[tauri::command]
+pub fn get_close_protection(exit_state: tauri::State<ExitState>) -> bool {
+    exit_state.close_protected.load(Ordering::SeqCst)
+}
+
+/// Closes the main window despite close protection, going through the usual
+/// `before-exit` handshake
+#[cfg(desktop)]
+#[tauri::command]
+pub fn force_close(
+    app_handle: tauri::AppHandle,
+    exit_state: tauri::State<ExitState>,
+) -> Result<(), String> {
+    let window = app_handle
+        .get_webview_window(crate::window::MAIN_WINDOW_LABEL)
+        .ok_or_else(|| "Main window not found".to_string())?;
+    info!(target: "window-event", "Force closing the main window");
+    exit_state.force_close.store(true, Ordering::SeqCst);
+    window
+        .close()
+        .map_err(|e| format!("Failed to close main window: {e}"))
+}
+
+/// Gets CPU and memory usage of the sidecar server, or None if it isn't running
+#[cfg(desktop)]
+#[tauri::command]
+pub async fn get_server_resource_usage(
+    app_handle: tauri::AppHandle,
+) -> Result<Option<ServerResourceUsage>, String> {
+    let Some(pid) = crate::server::sidecar_pid(&app_handle) else {
+        return Ok(None);
+    };
+    tokio::task::spawn_blocking(move || crate::server::process_resource_usage(pid))
+        .await
+        .map_err(|e| format!("Failed to sample server resource usage: {e}"))
+}
+
+/// Lists sidecar processes left running by an earlier crash (not the current sidecar)
+#[cfg(desktop)]
+#[tauri::command]
+pub async fn list_orphaned_sidecars(
+    app_handle: tauri::AppHandle,
+) -> Result<Vec<crate::server::OrphanedSidecar>, String> {
+    tokio::task::spawn_blocking(move || crate::server::find_orphaned_sidecars(&app_handle))
+        .await
+        .map_err(|e| format!("Failed to list orphaned sidecars: {e}"))?
+}
+
+/// Kills sidecar processes left running by an earlier crash, returning their PIDs
+#[cfg(desktop)]
+#[tauri::command]
+pub async fn kill_orphaned_sidecars(app_handle: tauri::AppHandle) -> Result<Vec<u32>, String> {
+    tokio::task::spawn_blocking(move || crate::server::kill_orphaned_sidecars(&app_handle))
+        .await
+        .map_err(|e| format!("Failed to kill orphaned sidecars: {e}"))?
+}
+
+/// How long `ping_server` waits for the sidecar to answer
+#[cfg(desktop)]
+const PING_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Measures the round-trip time of a request to the sidecar's /ping endpoint,
+/// along with a rolling average of recent pings
+#[cfg(desktop)]
+#[tauri::command]
+pub async fn ping_server(app_state: tauri::State<'_, AppState>) -> Result<ServerPing, String> {
+    let port = app_state.server_port;
+    let latency =
+        tokio::task::spawn_blocking(move || crate::server::ping_server_latency(port, PING_TIMEOUT))
+            .await
+            .map_err(|e| format!("Failed to ping server: {e}"))??;
+
+    Ok(app_state.record_server_ping(latency.as_secs_f64() * 1000.0))
+}
+
 /// Restart the sidecar server (database connection will be re-initialized)
+/// Progress is emitted as `server-restart-progress` events
 #[cfg(desktop)]
 #[tauri::command]
 pub async fn restart_server(app_handle: tauri::AppHandle) -> Result<(), String> {
     crate::server::restart_server_async(&app_handle).await
 }
+
+/// Shortest and longest health check timeouts accepted, so a tiny timeout can't restart a
+/// healthy server and a huge one can't hang the check
+#[cfg(desktop)]
+const MIN_HEALTH_CHECK_TIMEOUT_MS: u64 = 100;
+#[cfg(desktop)]
+const MAX_HEALTH_CHECK_TIMEOUT_MS: u64 = 30_000;
+
+/// Restarts the sidecar only if it doesn't answer /ping within `timeout_ms`
+/// Returns whether a restart happened (false means the server was healthy)
+#[cfg(desktop)]
+#[tauri::command]
+pub async fn restart_server_if_unhealthy(
+    app_handle: tauri::AppHandle,
+    timeout_ms: u64,
+) -> Result<bool, String> {
+    if !(MIN_HEALTH_CHECK_TIMEOUT_MS..=MAX_HEALTH_CHECK_TIMEOUT_MS).contains(&timeout_ms) {
+        return Err(format!(
+            "Timeout must be between {MIN_HEALTH_CHECK_TIMEOUT_MS} and {MAX_HEALTH_CHECK_TIMEOUT_MS}ms, got {timeout_ms}"
+        ));
+    }
+    let port = app_handle.state::<AppState>().server_port;
+    let timeout = Duration::from_millis(timeout_ms);
+    let responding =
+        tokio::task::spawn_blocking(move || crate::server::is_server_responding(port, timeout))
+            .await
+            .map_err(|e| format!("Failed to check server health: {e}"))?;
+
+    if responding {
+        info!(target: "sidecar", "Server is healthy, not restarting");
+        return Ok(false);
+    }
+
+    info!(target: "sidecar", "Server did not respond within {timeout_ms}ms, restarting");
+    crate::server::restart_server_async(&app_handle).await?;
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn import_path_round_trips() {
+        let path = PathBuf::from("/Users/ana/Slujbă de duminică/Cântări #12.pptx");
+        let encoded = encode_import_path(&path);
+        assert_eq!(encoded, path.to_str().unwrap());
+        assert_eq!(decode_import_path(&encoded), path);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn non_utf8_import_path_round_trips() {
+        use std::ffi::OsStr;
+        use std::os::unix::ffi::OsStrExt;
+
+        let path = Path::new(OsStr::from_bytes(b"/tmp/Cantari \xff #12.pptx"));
+        let encoded = encode_import_path(path);
+        assert!(encoded.starts_with("file://"));
+        assert_eq!(decode_import_path(&encoded), path);
+    }
+}
@@ -0,0 +1,232 @@
+use crate::store;
+use crate::window::MAIN_WINDOW_LABEL;
+use log::{info, warn};
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+use tauri::http::HeaderMap;
+use tauri::ipc::{CallbackFn, InvokeBody, InvokeResponse};
+use tauri::webview::InvokeRequest;
+use tauri::{AppHandle, Emitter, Manager};
+
+/// Store key for the saved cue recordings (name -> cues)
+pub const CUE_RECORDINGS_KEY: &str = "cueRecordings";
+
+/// Commands captured while recording: the display, webview and volume cues an operator
+/// fires during a service (queries and settings are left out)
+const RECORDED_COMMANDS: &[&str] = &[
+    "create_child_webview",
+    "ensure_display",
+    "show_child_webview",
+    "hide_child_webview",
+    "close_child_webview",
+    "update_child_webview",
+    "suspend_child_webview",
+    "resume_child_webview",
+    "webview_post_message",
+    "set_display_fullscreen",
+    "set_display_kiosk",
+    "set_display_monitor",
+    "apply_display_preset",
+    "mirror_display",
+    "unmirror_display",
+    "set_system_volume",
+];
+
+/// Fastest and slowest replay speeds accepted
+const MIN_REPLAY_SPEED: f64 = 0.1;
+const MAX_REPLAY_SPEED: f64 = 10.0;
+
+/// A command captured by the cue recorder
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct Cue {
+    /// Time since the recording started
+    pub offset_ms: u64,
+    pub command: String,
+    pub args: Value,
+}
+
+/// State of the cue recorder and replay
+#[derive(Default)]
+pub struct CueState {
+    /// Start time and cues of the recording in progress
+    pub recording: Mutex<Option<(Instant, Vec<Cue>)>>,
+    /// Bumped to cancel the replay in progress
+    pub replay_generation: AtomicU64,
+}
+
+/// Records an invoked command if a recording is in progress and the command is a cue.
+/// Called from the invoke handler for every command.
+pub fn record_invoke(app: &AppHandle, command: &str, payload: &InvokeBody) {
+    if !RECORDED_COMMANDS.contains(&command) {
+        return;
+    }
+    let Some(state) = app.try_state::<CueState>() else {
+        return;
+    };
+    let mut recording = state.recording.lock();
+    let Some((started, cues)) = recording.as_mut() else {
+        return;
+    };
+    let args = match payload {
+        InvokeBody::Json(args) => args.clone(),
+        InvokeBody::Raw(_) => Value::Null,
+    };
+    cues.push(Cue {
+        offset_ms: started.elapsed().as_millis() as u64,
+        command: command.to_string(),
+        args,
+    });
+}
+
+/// Reads the saved recordings
+fn saved_recordings(app: &AppHandle) -> BTreeMap<String, Vec<Cue>> {
+    store::get_value(app, CUE_RECORDINGS_KEY).unwrap_or_default()
+}
+
+/// Starts recording display/webview/volume commands, discarding any unfinished recording
+#[tauri::command]
+pub fn start_cue_recording(state: tauri::State<'_, CueState>) {
+    info!(target: "cues", "Recording cues");
+    *state.recording.lock() = Some((Instant::now(), Vec::new()));
+}
+
+/// Stops recording and returns the cues, saving them under `name` when given
+#[tauri::command]
+pub fn stop_cue_recording(
+    app: AppHandle,
+    state: tauri::State<'_, CueState>,
+    name: Option<String>,
+) -> Result<Vec<Cue>, String> {
+    let (_, cues) = state
+        .recording
+        .lock()
+        .take()
+        .ok_or_else(|| "No cue recording in progress".to_string())?;
+    info!(target: "cues", "Recorded {} cues", cues.len());
+
+    if let Some(name) = name.map(|name| name.trim().to_string()) {
+        if name.is_empty() {
+            return Err("Recording name cannot be empty".to_string());
+        }
+        let mut recordings = saved_recordings(&app);
+        recordings.insert(name, cues.clone());
+        store::set_value(&app, CUE_RECORDINGS_KEY, &recordings)?;
+    }
+    Ok(cues)
+}
+
+/// Lists the names of the saved recordings
+#[tauri::command]
+pub fn list_cue_recordings(app: AppHandle) -> Vec<String> {
+    saved_recordings(&app).into_keys().collect()
+}
+
+/// Gets a saved recording
+#[tauri::command]
+pub fn get_cue_recording(app: AppHandle, name: String) -> Result<Vec<Cue>, String> {
+    saved_recordings(&app)
+        .remove(&name)
+        .ok_or_else(|| format!("Cue recording '{}' not found", name))
+}
+
+/// Deletes a saved recording, returning whether it existed
+#[tauri::command]
+pub fn delete_cue_recording(app: AppHandle, name: String) -> Result<bool, String> {
+    let mut recordings = saved_recordings(&app);
+    if recordings.remove(&name).is_none() {
+        return Ok(false);
+    }
+    store::set_value(&app, CUE_RECORDINGS_KEY, &recordings)?;
+    Ok(true)
+}
+
+/// Invokes a recorded command as if the main window had sent it, so it goes through the
+/// same handler and capability checks as a live cue. Failures are only logged, so one
+/// bad cue doesn't stop the rest of the replay.
+fn dispatch_cue(app: &AppHandle, cue: Cue) {
+    let Some(window) = app.get_webview_window(MAIN_WINDOW_LABEL) else {
+        warn!(target: "cues", "Main window is gone, skipping cue '{}'", cue.command);
+        return;
+    };
+    let webview = window.as_ref().clone();
+    let url = match webview.url() {
+        Ok(url) => url,
+        Err(e) => {
+            warn!(target: "cues", "Failed to get main window URL: {}", e);
+            return;
+        }
+    };
+    let request = InvokeRequest {
+        cmd: cue.command,
+        callback: CallbackFn(0),
+        error: CallbackFn(1),
+        url,
+        body: InvokeBody::Json(cue.args),
+        headers: HeaderMap::new(),
+        invoke_key: app.invoke_key().to_string(),
+    };
+
+    // Commands expect to be invoked from the main thread, like the webview IPC does
+    let result = app.run_on_main_thread(move || {
+        webview.on_message(
+            request,
+            Box::new(|_, command, response, _, _| {
+                if let InvokeResponse::Err(e) = response {
+                    warn!(target: "cues", "Replayed cue '{}' failed: {}", command, e.0);
+                }
+            }),
+        );
+    });
+    if let Err(e) = result {
+        warn!(target: "cues", "Failed to dispatch cue: {}", e);
+    }
+}
+
+/// Replays cues with their original relative timing scaled by `speed` (2.0 = twice as
+/// fast), replacing any replay in progress. Each cue is invoked natively on behalf of the
+/// main window; `cue-replay-finished` is emitted to it after the last one.
+#[tauri::command]
+pub fn replay_cues(
+    app: AppHandle,
+    state: tauri::State<'_, CueState>,
+    cues: Vec<Cue>,
+    speed: Option<f64>,
+) -> Result<(), String> {
+    let speed = speed.unwrap_or(1.0);
+    if !(MIN_REPLAY_SPEED..=MAX_REPLAY_SPEED).contains(&speed) {
+        return Err(format!(
+            "Replay speed must be between {} and {}",
+            MIN_REPLAY_SPEED, MAX_REPLAY_SPEED
+        ));
+    }
+    let generation = state.replay_generation.fetch_add(1, Ordering::SeqCst) + 1;
+    info!(target: "cues", "Replaying {} cues at {}x", cues.len(), speed);
+
+    tauri::async_runtime::spawn(async move {
+        let started = tokio::time::Instant::now();
+        for cue in cues {
+            let at = Duration::from_millis(cue.offset_ms).div_f64(speed);
+            tokio::time::sleep_until(started + at).await;
+            let state = app.state::<CueState>();
+            if state.replay_generation.load(Ordering::SeqCst) != generation {
+                return;
+            }
+            dispatch_cue(&app, cue);
+        }
+        if let Err(e) = app.emit_to(MAIN_WINDOW_LABEL, "cue-replay-finished", ()) {
+            warn!(target: "cues", "Failed to emit cue-replay-finished: {}", e);
+        }
+    });
+    Ok(())
+}
+
+/// Stops the replay in progress, if any
+#[tauri::command]
+pub fn stop_cue_replay(state: tauri::State<'_, CueState>) {
+    state.replay_generation.fetch_add(1, Ordering::SeqCst);
+}
@@ -0,0 +1,96 @@
+use crate::url_import::{import_from_url, parse_import_link};
+use log::{info, warn};
+use parking_lot::Mutex;
+use serde::Serialize;
+use std::collections::{BTreeMap, VecDeque};
+use tauri::{AppHandle, Emitter, Manager, Url};
+
+/// Custom URL scheme registered for the app (Info.plist, NSIS hooks, Linux desktop entry)
+///
+/// Links have the form `churchhub://<route>?<params>`, e.g.
+/// `churchhub://services/42?tab=plan` opens route `/services/42` with `{ tab: "plan" }`.
+/// `churchhub://import?url=...` is reserved for importing a linked file (see `url_import`).
+pub const URL_SCHEME: &str = "churchhub";
+
+/// A parsed `churchhub://` link, emitted to the frontend as `deep-link`
+#[derive(Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeepLink {
+    pub url: String,
+    pub route: String,
+    pub params: BTreeMap<String, String>,
+}
+
+/// State for storing deep links received before the frontend could handle them (oldest first)
+#[derive(Default)]
+pub struct PendingDeepLink {
+    pub links: Mutex<VecDeque<DeepLink>>,
+}
+
+/// Checks if a launch argument is a `churchhub://` link
+pub fn is_deep_link(arg: &str) -> bool {
+    Url::parse(arg).is_ok_and(|url| url.scheme() == URL_SCHEME)
+}
+
+/// Parses a `churchhub://` link into its route and query params
+pub fn parse_deep_link(link: &str) -> Option<DeepLink> {
+    let url = Url::parse(link).ok()?;
+    if url.scheme() != URL_SCHEME {
+        return None;
+    }
+
+    // The host is the first route segment (`churchhub://services/42`), but also
+    // accept an empty host (`churchhub:///services/42`)
+    let host = url.host_str().unwrap_or_default();
+    let path = url.path().trim_start_matches('/');
+    let route = [host, path]
+        .iter()
+        .filter(|segment| !segment.is_empty())
+        .copied()
+        .collect::<Vec<_>>()
+        .join("/");
+
+    Some(DeepLink {
+        url: url.to_string(),
+        route: format!("/{}", route.trim_end_matches('/')),
+        params: url.query_pairs().into_owned().collect(),
+    })
+}
+
+/// Handles a `churchhub://` link: import links download and queue the file, any other
+/// link is queued for `get_pending_deep_link` and emitted as `deep-link`.
+/// The frontend should listen for `deep-link` and also drain `get_pending_deep_link`
+/// on load, since a link that launched the app arrives before the listener exists.
+/// Returns false if the argument isn't a `churchhub://` link.
+pub fn handle_deep_link(app: &AppHandle, link: &str) -> bool {
+    if let Some((url, format)) = parse_import_link(link) {
+        import_from_url(app, url, format);
+        return true;
+    }
+
+    let Some(deep_link) = parse_deep_link(link) else {
+        return false;
+    };
+    info!(target: "deep-link", "Opening route {}", deep_link.route);
+
+    if let Some(pending) = app.try_state::<PendingDeepLink>() {
+        pending.links.lock().push_back(deep_link.clone());
+    }
+
+    if let Err(e) = app.emit("deep-link", deep_link) {
+        warn!(target: "deep-link", "Failed to emit deep-link: {e}");
+    }
+
+    // Bring the main window to front
+    if let Some(window) = app.get_webview_window("main") {
+        let _ = window.unminimize();
+        let _ = window.set_focus();
+    }
+    true
+}
+
+/// Takes the next deep link the frontend hasn't handled yet
+#[tauri::command]
+pub fn get_pending_deep_link(state: tauri::State<PendingDeepLink>) -> Option<DeepLink> {
+    state.links.lock().pop_front()
+}
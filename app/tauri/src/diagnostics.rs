@@ -0,0 +1,228 @@
+use crate::domain::{AppState, ServerResourceUsage, StartupMetrics, StartupPhase};
+use serde::Serialize;
+use std::sync::Arc;
+use std::time::Duration;
+use tauri::{AppHandle, Manager, PhysicalPosition, PhysicalSize};
+
+/// Number of sidecar log lines attached to a diagnostics bundle
+const LOG_TAIL_LINES: usize = 50;
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Diagnostics {
+    pub os: String,
+    pub os_family: String,
+    pub arch: String,
+    pub server_port: u16,
+    pub server_responding: bool,
+    /// Round-trip time of a single /ping, if the server responded
+    pub server_latency_ms: Option<f64>,
+    pub sidecar_running: bool,
+    pub sidecar_resource_usage: Option<ServerResourceUsage>,
+    pub sidecar_log_tail: Vec<String>,
+    pub startup_metrics: Vec<StartupPhase>,
+    /// App, Tauri, webview and sidecar versions, serialized alongside the other fields
+    #[serde(flatten)]
+    pub component_versions: ComponentVersions,
+}
+
+/// Versions of the app's components, for support triage
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ComponentVersions {
+    pub app_version: String,
+    pub tauri_version: String,
+    /// WebView2 on Windows, WKWebView on macOS, WebKitGTK on Linux
+    pub webview_engine: &'static str,
+    pub webview_version: Option<String>,
+    /// As reported by the sidecar's /version endpoint, if it answered
+    pub server_version: Option<String>,
+}
+
+/// Name of the platform webview engine
+fn webview_engine() -> &'static str {
+    if cfg!(target_os = "windows") {
+        "WebView2"
+    } else if cfg!(target_os = "macos") {
+        "WKWebView"
+    } else {
+        "WebKitGTK"
+    }
+}
+
+/// Collects the component versions (queries the sidecar, so it blocks up to 500ms)
+async fn component_versions(app: &AppHandle, server_port: u16) -> ComponentVersions {
+    let server_version = tokio::task::spawn_blocking(move || {
+        crate::server::fetch_server_version(server_port, Duration::from_millis(500)).ok()
+    })
+    .await
+    .ok()
+    .flatten();
+
+    ComponentVersions {
+        app_version: app.package_info().version.to_string(),
+        tauri_version: tauri::VERSION.to_string(),
+        webview_engine: webview_engine(),
+        webview_version: tauri::webview_version().ok(),
+        server_version,
+    }
+}
+
+/// Gets the versions of the app, Tauri, the webview runtime and the sidecar
+#[tauri::command]
+pub async fn get_component_versions(app: AppHandle) -> Result<ComponentVersions, String> {
+    let server_port = app
+        .try_state::<AppState>()
+        .ok_or_else(|| "App state not initialized".to_string())?
+        .server_port;
+    Ok(component_versions(&app, server_port).await)
+}
+
+/// Replaces the user's home directory with `~` so reports don't leak usernames
+pub fn redact_user_paths(app: &AppHandle, text: &str) -> String {
+    match app.path().home_dir() {
+        Ok(home) => {
+            let home = home.to_string_lossy();
+            if home.is_empty() {
+                text.to_string()
+            } else {
+                text.replace(home.as_ref(), "~")
+            }
+        }
+        Err(_) => text.to_string(),
+    }
+}
+
+/// Collects system and app diagnostics to attach to feedback reports
+#[tauri::command]
+pub async fn collect_diagnostics(app: AppHandle) -> Result<Diagnostics, String> {
+    let app_state = app
+        .try_state::<AppState>()
+        .ok_or_else(|| "App state not initialized".to_string())?;
+
+    let server_port = app_state.server_port;
+    let sidecar_pid = crate::server::sidecar_pid(&app);
+    let sidecar_running = sidecar_pid.is_some();
+    let sidecar_log_tail: Vec<String> = {
+        let logs = app_state.server_logs.lock();
+        let skip = logs.len().saturating_sub(LOG_TAIL_LINES);
+        logs.iter()
+            .skip(skip)
+            .map(|line| redact_user_paths(&app, line))
+            .collect()
+    };
+
+    let (server_latency, sidecar_resource_usage) = tokio::task::spawn_blocking(move || {
+        (
+            crate::server::ping_server_latency(server_port, Duration::from_millis(500)).ok(),
+            sidecar_pid.and_then(crate::server::process_resource_usage),
+        )
+    })
+    .await
+    .unwrap_or((None, None));
+    let server_responding = server_latency.is_some();
+    let server_latency_ms = server_latency.map(|latency| latency.as_secs_f64() * 1000.0);
+
+    let startup_metrics = app
+        .try_state::<Arc<StartupMetrics>>()
+        .map(|metrics| metrics.phases.lock().clone())
+        .unwrap_or_default();
+
+    let component_versions = component_versions(&app, server_port).await;
+
+    Ok(Diagnostics {
+        os: std::env::consts::OS.to_string(),
+        os_family: std::env::consts::FAMILY.to_string(),
+        arch: std::env::consts::ARCH.to_string(),
+        server_port,
+        server_responding,
+        server_latency_ms,
+        sidecar_running,
+        sidecar_resource_usage,
+        sidecar_log_tail,
+        startup_metrics,
+        component_versions,
+    })
+}
+
+/// A child webview in the webview tree
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WebviewNode {
+    pub label: String,
+    pub url: Option<String>,
+    /// Position relative to the parent window, in physical pixels
+    pub position: Option<PhysicalPosition<i32>>,
+    pub size: Option<PhysicalSize<u32>>,
+    pub visible: bool,
+}
+
+/// A window and the webviews it hosts
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WindowNode {
+    pub label: String,
+    pub title: Option<String>,
+    /// Outer position on the virtual desktop, in physical pixels
+    pub position: Option<PhysicalPosition<i32>>,
+    /// Inner size, in physical pixels
+    pub size: Option<PhysicalSize<u32>>,
+    pub scale_factor: Option<f64>,
+    pub monitor: Option<String>,
+    pub visible: bool,
+    pub focused: bool,
+    pub minimized: bool,
+    pub fullscreen: bool,
+    pub webviews: Vec<WebviewNode>,
+}
+
+/// Lists every window with its webviews (labels, URLs, bounds, visibility) for
+/// remote support. Webviews are grouped under the window they are attached to.
+#[tauri::command]
+pub fn dump_webview_tree(app: AppHandle) -> Vec<WindowNode> {
+    let mut windows: Vec<WindowNode> = app
+        .windows()
+        .into_values()
+        .map(|window| WindowNode {
+            label: window.label().to_string(),
+            title: window.title().ok(),
+            position: window.outer_position().ok(),
+            size: window.inner_size().ok(),
+            scale_factor: window.scale_factor().ok(),
+            monitor: window
+                .current_monitor()
+                .ok()
+                .flatten()
+                .and_then(|monitor| monitor.name().cloned()),
+            visible: window.is_visible().unwrap_or(false),
+            focused: window.is_focused().unwrap_or(false),
+            minimized: window.is_minimized().unwrap_or(false),
+            fullscreen: window.is_fullscreen().unwrap_or(false),
+            webviews: Vec::new(),
+        })
+        .collect();
+    windows.sort_by(|a, b| a.label.cmp(&b.label));
+
+    let registry = app.state::<crate::webview::WebviewRegistry>();
+    for webview in app.webviews().into_values() {
+        let parent = webview.window().label().to_string();
+        let Some(window) = windows.iter_mut().find(|window| window.label == parent) else {
+            continue;
+        };
+        window.webviews.push(WebviewNode {
+            label: webview.label().to_string(),
+            url: webview
+                .url()
+                .ok()
+                .map(|url| redact_user_paths(&app, url.as_str())),
+            position: webview.position().ok(),
+            size: webview.size().ok(),
+            visible: !registry.is_hidden(webview.label()),
+        });
+    }
+    for window in &mut windows {
+        window.webviews.sort_by(|a, b| a.label.cmp(&b.label));
+    }
+
+    windows
+}
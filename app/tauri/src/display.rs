@@ -0,0 +1,788 @@
+use crate::store;
+use log::{info, warn};
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use std::collections::{hash_map::Entry, HashMap, HashSet};
+use std::path::Path;
+use std::process::Command;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tauri::image::Image;
+use tauri::{
+    AppHandle, Emitter, Listener, Manager, Monitor, PhysicalPosition, PhysicalSize, WebviewWindow,
+};
+
+/// Label prefix used by the frontend for projector/output windows
+pub const DISPLAY_WINDOW_PREFIX: &str = "display-";
+
+/// Checks if a window label belongs to a display window
+pub fn is_display_window(label: &str) -> bool {
+    label.starts_with(DISPLAY_WINDOW_PREFIX)
+}
+
+/// Window geometry saved before entering fullscreen so it can be restored
+#[derive(Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WindowGeometry {
+    pub position: PhysicalPosition<i32>,
+    pub size: PhysicalSize<u32>,
+    pub decorated: bool,
+    pub maximized: bool,
+}
+
+impl WindowGeometry {
+    /// Captures the current geometry of a window
+    pub fn capture(window: &WebviewWindow) -> Result<Self, String> {
+        Ok(Self {
+            position: window
+                .outer_position()
+                .map_err(|e| format!("Failed to get window position: {}", e))?,
+            size: window
+                .inner_size()
+                .map_err(|e| format!("Failed to get window size: {}", e))?,
+            decorated: window.is_decorated().unwrap_or(true),
+            maximized: window.is_maximized().unwrap_or(false),
+        })
+    }
+
+    /// Applies the saved geometry back to a window
+    pub fn restore(&self, window: &WebviewWindow) -> Result<(), String> {
+        window
+            .set_decorations(self.decorated)
+            .map_err(|e| format!("Failed to set decorations: {}", e))?;
+        if self.maximized {
+            window
+                .maximize()
+                .map_err(|e| format!("Failed to maximize window: {}", e))?;
+        } else {
+            window
+                .set_position(self.position)
+                .map_err(|e| format!("Failed to set window position: {}", e))?;
+            window
+                .set_size(self.size)
+                .map_err(|e| format!("Failed to set window size: {}", e))?;
+        }
+        Ok(())
+    }
+}
+
+/// State for tracking fullscreen/kiosk display windows
+#[derive(Default)]
+pub struct DisplayModeState {
+    pub saved_geometry: Mutex<HashMap<String, WindowGeometry>>,
+    pub kiosk_labels: Mutex<HashSet<String>>,
+    /// Windows switched to the display role, with how they looked before
+    pub display_roles: Mutex<HashMap<String, ControlRoleLook>>,
+}
+
+/// How a window looked in the control role, restored when it leaves the display role
+#[derive(Clone, Copy, Debug)]
+pub struct ControlRoleLook {
+    pub always_on_top: bool,
+    pub decorated: bool,
+}
+
+impl Default for ControlRoleLook {
+    fn default() -> Self {
+        ControlRoleLook {
+            always_on_top: false,
+            decorated: true,
+        }
+    }
+}
+
+impl DisplayModeState {
+    /// Checks if a window is in kiosk mode (closing it is blocked)
+    pub fn is_kiosk(&self, label: &str) -> bool {
+        self.kiosk_labels.lock().contains(label)
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DisplayWindowInfo {
+    pub label: String,
+    pub title: String,
+    pub monitor_index: Option<usize>,
+    pub monitor_name: Option<String>,
+    pub visible: bool,
+}
+
+/// Finds the index of a monitor within the list of available monitors
+fn monitor_index(monitors: &[Monitor], monitor: &Monitor) -> Option<usize> {
+    monitors
+        .iter()
+        .position(|m| m.name() == monitor.name() && m.position() == monitor.position())
+}
+
+/// Gets a display window by label, failing if it doesn't exist or isn't a display window
+pub fn get_display_window(app: &AppHandle, label: &str) -> Result<WebviewWindow, String> {
+    if !is_display_window(label) {
+        return Err(format!("'{}' is not a display window", label));
+    }
+    app.get_webview_window(label)
+        .ok_or_else(|| format!("Display window '{}' not found", label))
+}
+
+/// Lists all open display windows with their monitor placement and visibility
+#[tauri::command]
+pub async fn list_display_windows(app: AppHandle) -> Result<Vec<DisplayWindowInfo>, String> {
+    let monitors = app
+        .available_monitors()
+        .map_err(|e| format!("Failed to list monitors: {}", e))?;
+
+    let mut displays: Vec<DisplayWindowInfo> = app
+        .webview_windows()
+        .into_iter()
+        .filter(|(label, _)| is_display_window(label))
+        .map(|(label, window)| {
+            let current_monitor = window.current_monitor().ok().flatten();
+            DisplayWindowInfo {
+                title: window.title().unwrap_or_default(),
+                monitor_index: current_monitor
+                    .as_ref()
+                    .and_then(|m| monitor_index(&monitors, m)),
+                monitor_name: current_monitor.as_ref().and_then(|m| m.name().cloned()),
+                visible: window.is_visible().unwrap_or(false),
+                label,
+            }
+        })
+        .collect();
+
+    displays.sort_by(|a, b| a.label.cmp(&b.label));
+    Ok(displays)
+}
+
+/// Brings a display window to the front and focuses it
+#[tauri::command]
+pub async fn focus_display_window(app: AppHandle, label: String) -> Result<(), String> {
+    info!(target: "display", "Focusing display window '{}'", label);
+
+    let window = get_display_window(&app, &label)?;
+    window
+        .unminimize()
+        .map_err(|e| format!("Failed to unminimize window: {}", e))?;
+    window
+        .show()
+        .map_err(|e| format!("Failed to show window: {}", e))?;
+    window
+        .set_focus()
+        .map_err(|e| format!("Failed to focus window: {}", e))?;
+
+    Ok(())
+}
+
+/// Sets the title of a display window, and optionally its icon (a PNG or ICO file),
+/// so each projector window is distinguishable in the taskbar and alt-tab
+#[tauri::command]
+pub async fn set_display_title(
+    app: AppHandle,
+    label: String,
+    title: String,
+    icon: Option<String>,
+) -> Result<(), String> {
+    info!(target: "display", "Setting title of '{}' to '{}'", label, title);
+
+    let window = get_display_window(&app, &label)?;
+    window
+        .set_title(&title)
+        .map_err(|e| format!("Failed to set window title: {}", e))?;
+    if let Some(icon) = icon {
+        let image = Image::from_path(&icon)
+            .map_err(|e| format!("Failed to load icon '{}': {}", icon, e))?;
+        window
+            .set_icon(image)
+            .map_err(|e| format!("Failed to set window icon: {}", e))?;
+    }
+    Ok(())
+}
+
+/// Enters fullscreen, remembering the windowed geometry (kept from the first call)
+pub fn enter_fullscreen(window: &WebviewWindow, state: &DisplayModeState) -> Result<(), String> {
+    if let Entry::Vacant(entry) = state
+        .saved_geometry
+        .lock()
+        .entry(window.label().to_string())
+    {
+        entry.insert(WindowGeometry::capture(window)?);
+    }
+    window
+        .set_fullscreen(true)
+        .map_err(|e| format!("Failed to enter fullscreen: {}", e))
+}
+
+/// Leaves fullscreen and restores the remembered windowed geometry
+pub fn exit_fullscreen(window: &WebviewWindow, state: &DisplayModeState) -> Result<(), String> {
+    window
+        .set_fullscreen(false)
+        .map_err(|e| format!("Failed to exit fullscreen: {}", e))?;
+    let saved = state.saved_geometry.lock().remove(window.label());
+    if let Some(geometry) = saved {
+        geometry.restore(window)?;
+    }
+    Ok(())
+}
+
+/// Enters or exits fullscreen on a display window
+#[tauri::command]
+pub async fn set_display_fullscreen(
+    app: AppHandle,
+    state: tauri::State<'_, DisplayModeState>,
+    label: String,
+    enabled: bool,
+) -> Result<(), String> {
+    info!(target: "display", "Setting fullscreen={} on '{}'", enabled, label);
+
+    let window = get_display_window(&app, &label)?;
+    if enabled {
+        enter_fullscreen(&window, &state)
+    } else {
+        // Leaving fullscreen also leaves kiosk mode
+        if state.kiosk_labels.lock().remove(&label) {
+            window
+                .set_closable(true)
+                .map_err(|e| format!("Failed to set closable: {}", e))?;
+        }
+        exit_fullscreen(&window, &state)
+    }
+}
+
+/// Enters or exits kiosk mode (fullscreen, no decorations, close blocked) on a display window
+#[tauri::command]
+pub async fn set_display_kiosk(
+    app: AppHandle,
+    state: tauri::State<'_, DisplayModeState>,
+    label: String,
+    enabled: bool,
+) -> Result<(), String> {
+    info!(target: "display", "Setting kiosk={} on '{}'", enabled, label);
+
+    let window = get_display_window(&app, &label)?;
+    if enabled {
+        enter_fullscreen(&window, &state)?;
+        window
+            .set_decorations(false)
+            .map_err(|e| format!("Failed to set decorations: {}", e))?;
+        window
+            .set_closable(false)
+            .map_err(|e| format!("Failed to set closable: {}", e))?;
+        state.kiosk_labels.lock().insert(label);
+    } else {
+        state.kiosk_labels.lock().remove(&label);
+        window
+            .set_closable(true)
+            .map_err(|e| format!("Failed to set closable: {}", e))?;
+        exit_fullscreen(&window, &state)?;
+    }
+    Ok(())
+}
+
+/// Store key for the display label -> monitor name assignments
+pub const MONITOR_ASSIGNMENTS_KEY: &str = "displayMonitorAssignments";
+
+/// How often monitors are checked for connects/disconnects
+const MONITOR_POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+#[derive(Serialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct MonitorInfo {
+    pub index: usize,
+    /// Identifier used for display assignments
+    pub name: Option<String>,
+    pub x: i32,
+    pub y: i32,
+    pub width: u32,
+    pub height: u32,
+    pub scale_factor: f64,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DisplayAssignment {
+    pub label: String,
+    pub monitor: String,
+    /// False while the assigned monitor is disconnected (placement is deferred)
+    pub connected: bool,
+}
+
+/// Describes the available monitors
+fn monitor_infos(monitors: &[Monitor]) -> Vec<MonitorInfo> {
+    monitors
+        .iter()
+        .enumerate()
+        .map(|(index, monitor)| MonitorInfo {
+            index,
+            name: monitor.name().cloned(),
+            x: monitor.position().x,
+            y: monitor.position().y,
+            width: monitor.size().width,
+            height: monitor.size().height,
+            scale_factor: monitor.scale_factor(),
+        })
+        .collect()
+}
+
+/// Lists the available monitors
+#[tauri::command]
+pub async fn list_monitors(app: AppHandle) -> Result<Vec<MonitorInfo>, String> {
+    let monitors = app
+        .available_monitors()
+        .map_err(|e| format!("Failed to list monitors: {}", e))?;
+    Ok(monitor_infos(&monitors))
+}
+
+/// Reads the persisted display label -> monitor name assignments
+pub fn monitor_assignments(app: &AppHandle) -> HashMap<String, String> {
+    store::get_value(app, MONITOR_ASSIGNMENTS_KEY).unwrap_or_default()
+}
+
+/// Moves a display window onto its assigned monitor.
+/// Returns false if it has no assignment or the monitor is disconnected.
+fn place_display_window(
+    window: &WebviewWindow,
+    assignments: &HashMap<String, String>,
+    monitors: &[Monitor],
+) -> Result<bool, String> {
+    let Some(monitor_name) = assignments.get(window.label()) else {
+        return Ok(false);
+    };
+    let Some(monitor) = monitors.iter().find(|m| m.name() == Some(monitor_name)) else {
+        warn!(
+            target: "display",
+            "Monitor '{}' for '{}' is disconnected, deferring placement",
+            monitor_name,
+            window.label()
+        );
+        return Ok(false);
+    };
+
+    if window
+        .current_monitor()
+        .ok()
+        .flatten()
+        .as_ref()
+        .map(|m| m.name())
+        == Some(Some(monitor_name))
+    {
+        return Ok(true);
+    }
+
+    info!(
+        target: "display",
+        "Moving '{}' to monitor '{}'",
+        window.label(),
+        monitor_name
+    );
+
+    // Fullscreen windows have to leave fullscreen to change monitor
+    let fullscreen = window.is_fullscreen().unwrap_or(false);
+    if fullscreen {
+        window
+            .set_fullscreen(false)
+            .map_err(|e| format!("Failed to exit fullscreen: {}", e))?;
+    }
+    window
+        .set_position(*monitor.position())
+        .map_err(|e| format!("Failed to set window position: {}", e))?;
+    if fullscreen {
+        window
+            .set_fullscreen(true)
+            .map_err(|e| format!("Failed to enter fullscreen: {}", e))?;
+    }
+    Ok(true)
+}
+
+/// Places every open display window on its assigned monitor, if connected
+pub fn apply_display_assignments(app: &AppHandle) {
+    let assignments = monitor_assignments(app);
+    if assignments.is_empty() {
+        return;
+    }
+    let Ok(monitors) = app.available_monitors() else {
+        return;
+    };
+
+    for (label, window) in app.webview_windows() {
+        if is_display_window(&label) {
+            if let Err(e) = place_display_window(&window, &assignments, &monitors) {
+                warn!(target: "display", "Failed to place '{}': {}", label, e);
+            }
+        }
+    }
+}
+
+/// Assigns a display window to a monitor by name (None clears the assignment)
+/// and moves it there now if it's open and the monitor is connected
+#[tauri::command]
+pub async fn set_display_monitor(
+    app: AppHandle,
+    label: String,
+    monitor: Option<String>,
+) -> Result<(), String> {
+    if !is_display_window(&label) {
+        return Err(format!("'{}' is not a display window", label));
+    }
+    info!(target: "display", "Assigning '{}' to monitor {:?}", label, monitor);
+
+    let mut assignments = monitor_assignments(&app);
+    match monitor {
+        Some(monitor) => assignments.insert(label.clone(), monitor),
+        None => assignments.remove(&label),
+    };
+    store::set_value(&app, MONITOR_ASSIGNMENTS_KEY, &assignments)?;
+
+    if let Some(window) = app.get_webview_window(&label) {
+        let monitors = app
+            .available_monitors()
+            .map_err(|e| format!("Failed to list monitors: {}", e))?;
+        place_display_window(&window, &assignments, &monitors)?;
+    }
+    Ok(())
+}
+
+/// Gets the display monitor assignments and whether each monitor is connected
+#[tauri::command]
+pub async fn get_display_assignments(app: AppHandle) -> Result<Vec<DisplayAssignment>, String> {
+    let monitors = app
+        .available_monitors()
+        .map_err(|e| format!("Failed to list monitors: {}", e))?;
+
+    let mut assignments: Vec<DisplayAssignment> = monitor_assignments(&app)
+        .into_iter()
+        .map(|(label, monitor)| DisplayAssignment {
+            connected: monitors.iter().any(|m| m.name() == Some(&monitor)),
+            label,
+            monitor,
+        })
+        .collect();
+    assignments.sort_by(|a, b| a.label.cmp(&b.label));
+    Ok(assignments)
+}
+
+#[derive(Deserialize)]
+struct WindowCreatedPayload {
+    label: String,
+}
+
+/// Places display windows on their assigned monitors when they open
+pub fn init_monitor_assignments(app: &AppHandle) {
+    let handle = app.clone();
+    app.listen_any("tauri://window-created", move |event| {
+        let Ok(payload) = serde_json::from_str::<WindowCreatedPayload>(event.payload()) else {
+            return;
+        };
+        if !is_display_window(&payload.label) {
+            return;
+        }
+        let assignments = monitor_assignments(&handle);
+        if let (Some(window), Ok(monitors)) = (
+            handle.get_webview_window(&payload.label),
+            handle.available_monitors(),
+        ) {
+            if let Err(e) = place_display_window(&window, &assignments, &monitors) {
+                warn!(target: "display", "Failed to place '{}': {}", payload.label, e);
+            }
+        }
+    });
+}
+
+/// Polls the monitor configuration, emitting `monitors-changed` with the current monitor
+/// list when a monitor is connected, disconnected or rearranged, and re-applying
+/// display assignments (covers placements deferred while a monitor was missing)
+pub fn watch_monitors(app: &AppHandle) {
+    let handle = app.clone();
+    tauri::async_runtime::spawn(async move {
+        let mut previous: Option<Vec<MonitorInfo>> = None;
+        loop {
+            tokio::time::sleep(MONITOR_POLL_INTERVAL).await;
+            let Ok(monitors) = handle.available_monitors() else {
+                continue;
+            };
+            let current = monitor_infos(&monitors);
+            if previous
+                .as_ref()
+                .is_some_and(|previous| *previous != current)
+            {
+                info!(
+                    target: "display",
+                    "Monitor configuration changed ({} monitors)",
+                    current.len()
+                );
+                if let Err(e) = handle.emit("monitors-changed", &current) {
+                    warn!(target: "display", "Failed to emit monitors-changed: {}", e);
+                }
+                apply_display_assignments(&handle);
+            }
+            previous = Some(current);
+        }
+    });
+}
+
+/// Best-effort color capabilities of the monitor a window is on
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DisplayColorInfo {
+    pub monitor_name: Option<String>,
+    pub scale_factor: f64,
+    /// Bits per pixel of the framebuffer (e.g. 24 for 8-bit color, 30 for 10-bit)
+    pub bit_depth: Option<u32>,
+    /// True for 10-bit or deeper framebuffers (wide gamut / HDR capable output)
+    pub wide_color: Option<bool>,
+    /// Not exposed by the OS tools we use; the webview's `(dynamic-range: high)`
+    /// media query is the reliable HDR check
+    pub hdr: Option<bool>,
+    /// Names of the fields that couldn't be determined on this platform
+    pub undetermined: Vec<&'static str>,
+}
+
+/// Reads the framebuffer depth of a display from `system_profiler`
+/// (e.g. "Framebuffer Depth: 30-Bit Color (ARGB2101010)")
+#[cfg(target_os = "macos")]
+fn display_bit_depth(monitor_name: Option<&str>) -> Option<u32> {
+    let output = Command::new("system_profiler")
+        .arg("SPDisplaysDataType")
+        .output()
+        .ok()?;
+    let text = String::from_utf8_lossy(&output.stdout);
+
+    // Prefer the section for this monitor, falling back to the first display listed
+    let section = monitor_name
+        .and_then(|name| text.find(&format!("{}:", name)))
+        .map(|start| &text[start..])
+        .unwrap_or(&text);
+    let depth_line = section
+        .lines()
+        .find(|line| line.trim_start().starts_with("Framebuffer Depth:"))?;
+    depth_line
+        .split(':')
+        .nth(1)?
+        .trim()
+        .split('-')
+        .next()?
+        .parse()
+        .ok()
+}
+
+/// Reads the bits per pixel of the video adapter (per adapter, not per monitor)
+#[cfg(target_os = "windows")]
+fn display_bit_depth(_monitor_name: Option<&str>) -> Option<u32> {
+    let output = Command::new("powershell")
+        .args([
+            "-NoProfile",
+            "-Command",
+            "(Get-CimInstance Win32_VideoController | Select-Object -First 1).CurrentBitsPerPixel",
+        ])
+        .output()
+        .ok()?;
+    String::from_utf8_lossy(&output.stdout).trim().parse().ok()
+}
+
+/// Reads the root window depth from `xdpyinfo` (X11 only)
+#[cfg(target_os = "linux")]
+fn display_bit_depth(_monitor_name: Option<&str>) -> Option<u32> {
+    let output = Command::new("xdpyinfo").output().ok()?;
+    let text = String::from_utf8_lossy(&output.stdout);
+    // "depth of root window:    24 planes"
+    text.lines()
+        .find(|line| line.trim_start().starts_with("depth of root window:"))?
+        .split(':')
+        .nth(1)?
+        .split_whitespace()
+        .next()?
+        .parse()
+        .ok()
+}
+
+/// Gets best-effort color info (bit depth, wide color, HDR) for the monitor a window is on
+/// (defaults to the main window). Fields that couldn't be determined are null and listed
+/// in `undetermined`.
+#[tauri::command]
+pub async fn get_display_color_info(
+    app: AppHandle,
+    label: Option<String>,
+) -> Result<DisplayColorInfo, String> {
+    let window = crate::window::get_window(&app, label.as_deref())?;
+    let monitor = window
+        .current_monitor()
+        .map_err(|e| format!("Failed to get current monitor: {}", e))?;
+    let monitor_name = monitor.as_ref().and_then(|m| m.name().cloned());
+    let scale_factor = match &monitor {
+        Some(monitor) => monitor.scale_factor(),
+        None => window
+            .scale_factor()
+            .map_err(|e| format!("Failed to get scale factor: {}", e))?,
+    };
+
+    let name = monitor_name.clone();
+    let bit_depth = tokio::task::spawn_blocking(move || display_bit_depth(name.as_deref()))
+        .await
+        .map_err(|e| format!("Color info task failed: {}", e))?;
+    let wide_color = bit_depth.map(|depth| depth >= 30);
+    let hdr = None;
+
+    let undetermined = [
+        ("monitorName", monitor_name.is_none()),
+        ("bitDepth", bit_depth.is_none()),
+        ("wideColor", wide_color.is_none()),
+        ("hdr", hdr.is_none()),
+    ]
+    .into_iter()
+    .filter(|(_, missing)| *missing)
+    .map(|(field, _)| field)
+    .collect();
+
+    Ok(DisplayColorInfo {
+        monitor_name,
+        scale_factor,
+        bit_depth,
+        wide_color,
+        hdr,
+        undetermined,
+    })
+}
+
+/// Screen area to capture, in physical pixels on the virtual desktop
+struct CaptureRegion {
+    x: i32,
+    y: i32,
+    width: u32,
+    height: u32,
+    /// Only needed where the capture tool works in logical points (macOS)
+    #[cfg_attr(not(target_os = "macos"), allow(dead_code))]
+    scale_factor: f64,
+}
+
+/// Runs a capture tool, turning a spawn failure or non-zero exit into an error
+fn run_capture_tool(command: &mut Command) -> Result<(), String> {
+    let output = command
+        .output()
+        .map_err(|e| format!("Failed to run screenshot tool: {}", e))?;
+    if !output.status.success() {
+        return Err(format!(
+            "Screenshot tool failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(target_os = "macos")]
+fn capture_region(region: &CaptureRegion, path: &Path) -> Result<(), String> {
+    // screencapture expects points, not physical pixels
+    let to_points = |value: f64| (value / region.scale_factor).round() as i64;
+    run_capture_tool(Command::new("screencapture").args([
+        "-x".to_string(),
+        format!(
+            "-R{},{},{},{}",
+            to_points(region.x as f64),
+            to_points(region.y as f64),
+            to_points(region.width as f64),
+            to_points(region.height as f64)
+        ),
+        path.to_string_lossy().to_string(),
+    ]))
+}
+
+#[cfg(target_os = "windows")]
+fn capture_region(region: &CaptureRegion, path: &Path) -> Result<(), String> {
+    // Without DPI awareness CopyFromScreen works in scaled coordinates and crops the wrong
+    // area on monitors above 100% scaling
+    let script = format!(
+        "Add-Type -Name Dpi -Namespace ChurchHub -MemberDefinition \
+         '[DllImport(''user32.dll'')] public static extern bool SetProcessDPIAware();'; \
+         [ChurchHub.Dpi]::SetProcessDPIAware() | Out-Null; \
+         Add-Type -AssemblyName System.Drawing; \
+         $bitmap = New-Object System.Drawing.Bitmap({w}, {h}); \
+         $graphics = [System.Drawing.Graphics]::FromImage($bitmap); \
+         $graphics.CopyFromScreen({x}, {y}, 0, 0, $bitmap.Size); \
+         $bitmap.Save('{path}', [System.Drawing.Imaging.ImageFormat]::Png)",
+        w = region.width,
+        h = region.height,
+        x = region.x,
+        y = region.y,
+        path = path.to_string_lossy().replace('\'', "''"),
+    );
+    run_capture_tool(Command::new("powershell").args(["-NoProfile", "-Command", script.as_str()]))
+}
+
+#[cfg(target_os = "linux")]
+fn capture_region(region: &CaptureRegion, path: &Path) -> Result<(), String> {
+    let output_path = path.to_string_lossy().to_string();
+
+    // Wayland: grim, X11: ImageMagick's import
+    let grim = run_capture_tool(Command::new("grim").args([
+        "-g".to_string(),
+        format!(
+            "{},{} {}x{}",
+            region.x, region.y, region.width, region.height
+        ),
+        output_path.clone(),
+    ]));
+    if grim.is_ok() {
+        return Ok(());
+    }
+
+    run_capture_tool(Command::new("import").args([
+        "-window".to_string(),
+        "root".to_string(),
+        "-crop".to_string(),
+        format!(
+            "{}x{}+{}+{}",
+            region.width, region.height, region.x, region.y
+        ),
+        "+repage".to_string(),
+        output_path,
+    ]))
+    .map_err(|e| format!("{} (install grim or ImageMagick)", e))
+}
+
+/// Captures everything shown on a display window (including native video overlays
+/// a webview capture can't see) to a PNG in the temp directory and returns its path
+#[tauri::command]
+pub async fn capture_display(app: AppHandle, label: String) -> Result<String, String> {
+    info!(target: "display", "Capturing display window '{}'", label);
+
+    let window = get_display_window(&app, &label)?;
+    let position = window
+        .outer_position()
+        .map_err(|e| format!("Failed to get window position: {}", e))?;
+    let size = window
+        .outer_size()
+        .map_err(|e| format!("Failed to get window size: {}", e))?;
+    let region = CaptureRegion {
+        x: position.x,
+        y: position.y,
+        width: size.width,
+        height: size.height,
+        scale_factor: window.scale_factor().unwrap_or(1.0),
+    };
+
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis())
+        .unwrap_or_default();
+    // The label comes from the frontend, so keep it from adding path separators or `..`
+    let file_label: String = label
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    let path = std::env::temp_dir().join(format!("church-hub-{}-{}.png", file_label, timestamp));
+
+    let path = tokio::task::spawn_blocking(move || {
+        capture_region(&region, &path)?;
+        if !path.is_file() {
+            return Err("Screenshot tool did not produce an image".to_string());
+        }
+        Ok(path)
+    })
+    .await
+    .map_err(|e| format!("Capture task failed: {}", e))??;
+
+    info!(target: "display", "Display '{}' captured to {:?}", label, path);
+    Ok(path.to_string_lossy().to_string())
+}
@@ -0,0 +1,150 @@
+use log::{info, warn};
+use parking_lot::Mutex;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+use tauri::{AppHandle, Emitter, Manager};
+
+/// How often mirror sources are checked for navigation
+const SYNC_INTERVAL: Duration = Duration::from_millis(250);
+
+/// State of the display mirrors
+#[derive(Default)]
+pub struct MirrorState {
+    /// Mirrored webviews: target label -> source label
+    pub mirrors: Mutex<BTreeMap<String, String>>,
+    /// Whether the sync loop is running (only changed while holding `mirrors`)
+    pub watching: AtomicBool,
+}
+
+/// Payload of the `display-unmirrored` event
+#[derive(Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DisplayUnmirrored {
+    pub target: String,
+    pub source: String,
+    /// Why the mirror stopped: `sourceClosed` or `targetClosed`
+    pub reason: &'static str,
+}
+
+/// Points the target at the source's current URL if they differ
+fn sync_target(app: &AppHandle, source: &str, target: &str) -> Result<(), String> {
+    let source_webview = app
+        .get_webview(source)
+        .ok_or_else(|| format!("Webview '{}' not found", source))?;
+    let target_webview = app
+        .get_webview(target)
+        .ok_or_else(|| format!("Webview '{}' not found", target))?;
+
+    let url = source_webview
+        .url()
+        .map_err(|e| format!("Failed to get source URL: {}", e))?;
+    if target_webview.url().ok().as_ref() != Some(&url) {
+        info!(target: "display", "Mirroring {} from '{}' to '{}'", url, source, target);
+        target_webview
+            .navigate(url)
+            .map_err(|e| format!("Failed to navigate '{}': {}", target, e))?;
+    }
+    Ok(())
+}
+
+/// Stops a mirror whose source or target closed and tells the frontend
+fn stop_closed_mirror(app: &AppHandle, target: String, source: String) {
+    let reason = if app.get_webview(&source).is_none() {
+        "sourceClosed"
+    } else {
+        "targetClosed"
+    };
+    app.state::<MirrorState>().mirrors.lock().remove(&target);
+    info!(target: "display", "Stopped mirroring '{}' to '{}' ({})", source, target, reason);
+
+    let payload = DisplayUnmirrored {
+        target,
+        source,
+        reason,
+    };
+    if let Err(e) = app.emit("display-unmirrored", payload) {
+        warn!(target: "display", "Failed to emit display-unmirrored: {}", e);
+    }
+}
+
+/// Keeps a display showing the same page as another (e.g. an overflow room following the
+/// main projector): the target navigates whenever the source does. Replaces any mirror
+/// the target already had. The mirror stops on its own when either side closes.
+#[tauri::command]
+pub async fn mirror_display(
+    app: AppHandle,
+    state: tauri::State<'_, MirrorState>,
+    source_label: String,
+    target_label: String,
+) -> Result<(), String> {
+    if source_label == target_label {
+        return Err("A display cannot mirror itself".to_string());
+    }
+    // Following the chain back from the source must not lead to the target
+    let mut upstream = Some(source_label.clone());
+    while let Some(label) = upstream {
+        if label == target_label {
+            return Err(format!(
+                "'{}' already mirrors '{}'",
+                source_label, target_label
+            ));
+        }
+        upstream = state.mirrors.lock().get(&label).cloned();
+    }
+
+    sync_target(&app, &source_label, &target_label)?;
+    info!(target: "display", "Mirroring '{}' to '{}'", source_label, target_label);
+
+    let mut mirrors = state.mirrors.lock();
+    mirrors.insert(target_label, source_label);
+    if !state.watching.swap(true, Ordering::SeqCst) {
+        watch_mirrors(&app);
+    }
+    Ok(())
+}
+
+/// Stops mirroring into a display, leaving it on its current page.
+/// Returns whether it was mirroring.
+#[tauri::command]
+pub fn unmirror_display(state: tauri::State<'_, MirrorState>, target_label: String) -> bool {
+    let source = state.mirrors.lock().remove(&target_label);
+    if let Some(source) = &source {
+        info!(target: "display", "Stopped mirroring '{}' to '{}'", source, target_label);
+    }
+    source.is_some()
+}
+
+/// Lists the active mirrors (target label -> source label)
+#[tauri::command]
+pub fn list_display_mirrors(state: tauri::State<'_, MirrorState>) -> BTreeMap<String, String> {
+    state.mirrors.lock().clone()
+}
+
+/// Syncs mirror targets with their sources until no mirrors remain
+fn watch_mirrors(app: &AppHandle) {
+    let handle = app.clone();
+    tauri::async_runtime::spawn(async move {
+        loop {
+            tokio::time::sleep(SYNC_INTERVAL).await;
+
+            let mirrors = {
+                let state = handle.state::<MirrorState>();
+                let mirrors = state.mirrors.lock();
+                if mirrors.is_empty() {
+                    state.watching.store(false, Ordering::SeqCst);
+                    break;
+                }
+                mirrors.clone()
+            };
+            for (target, source) in mirrors {
+                if handle.get_webview(&source).is_none() || handle.get_webview(&target).is_none() {
+                    stop_closed_mirror(&handle, target, source);
+                } else if let Err(e) = sync_target(&handle, &source, &target) {
+                    warn!(target: "display", "{}", e);
+                }
+            }
+        }
+    });
+}
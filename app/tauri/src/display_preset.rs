@@ -0,0 +1,266 @@
+use crate::display::{self, is_display_window, DisplayModeState, WindowGeometry};
+use crate::store;
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use tauri::{AppHandle, Manager, PhysicalPosition, PhysicalSize, WebviewUrl, WebviewWindow};
+
+/// Store key for the saved display layout presets (name -> windows)
+pub const DISPLAY_PRESETS_KEY: &str = "displayPresets";
+
+/// A display window captured in a preset
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct DisplayPresetWindow {
+    pub label: String,
+    pub title: String,
+    pub url: String,
+    /// Monitor the window was on; `x`/`y` are relative to its origin when set
+    pub monitor: Option<String>,
+    pub x: i32,
+    pub y: i32,
+    pub width: u32,
+    pub height: u32,
+    pub fullscreen: bool,
+}
+
+#[derive(Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DisplayPreset {
+    pub name: String,
+    pub windows: Vec<DisplayPresetWindow>,
+}
+
+/// Reads the saved presets
+fn saved_presets(app: &AppHandle) -> BTreeMap<String, Vec<DisplayPresetWindow>> {
+    store::get_value(app, DISPLAY_PRESETS_KEY).unwrap_or_default()
+}
+
+/// Captures a display window's URL, monitor and windowed geometry
+fn capture_window(
+    window: &WebviewWindow,
+    state: &DisplayModeState,
+) -> Result<DisplayPresetWindow, String> {
+    let fullscreen = window.is_fullscreen().unwrap_or(false);
+    // Fullscreen windows keep the geometry to restore when leaving fullscreen
+    let saved = state.saved_geometry.lock().get(window.label()).copied();
+    let geometry = match saved {
+        Some(geometry) if fullscreen => geometry,
+        _ => WindowGeometry::capture(window)?,
+    };
+    let monitor = window.current_monitor().ok().flatten();
+    let origin = monitor
+        .as_ref()
+        .map(|m| *m.position())
+        .unwrap_or(PhysicalPosition::new(0, 0));
+
+    Ok(DisplayPresetWindow {
+        label: window.label().to_string(),
+        title: window.title().unwrap_or_default(),
+        url: window
+            .url()
+            .map_err(|e| format!("Failed to get window URL: {}", e))?
+            .to_string(),
+        monitor: monitor.and_then(|m| m.name().cloned()),
+        x: geometry.position.x - origin.x,
+        y: geometry.position.y - origin.y,
+        width: geometry.size.width,
+        height: geometry.size.height,
+        fullscreen,
+    })
+}
+
+/// Saves the open display windows (URLs, monitors, positions, fullscreen) as a preset,
+/// replacing any preset with the same name
+#[tauri::command]
+pub async fn save_display_preset(
+    app: AppHandle,
+    state: tauri::State<'_, DisplayModeState>,
+    name: String,
+) -> Result<DisplayPreset, String> {
+    let name = name.trim().to_string();
+    if name.is_empty() {
+        return Err("Preset name cannot be empty".to_string());
+    }
+
+    let mut windows = app
+        .webview_windows()
+        .into_values()
+        .filter(|window| is_display_window(window.label()))
+        .map(|window| capture_window(&window, &state))
+        .collect::<Result<Vec<_>, _>>()?;
+    if windows.is_empty() {
+        return Err("No display windows are open".to_string());
+    }
+    windows.sort_by(|a, b| a.label.cmp(&b.label));
+    info!(
+        target: "display",
+        "Saving preset '{}' with {} windows",
+        name,
+        windows.len()
+    );
+
+    let mut presets = saved_presets(&app);
+    presets.insert(name.clone(), windows.clone());
+    store::set_value(&app, DISPLAY_PRESETS_KEY, &presets)?;
+
+    Ok(DisplayPreset { name, windows })
+}
+
+/// Lists the saved presets, sorted by name
+#[tauri::command]
+pub fn list_display_presets(app: AppHandle) -> Vec<DisplayPreset> {
+    saved_presets(&app)
+        .into_iter()
+        .map(|(name, windows)| DisplayPreset { name, windows })
+        .collect()
+}
+
+/// Deletes a preset, returning whether it existed
+#[tauri::command]
+pub fn delete_display_preset(app: AppHandle, name: String) -> Result<bool, String> {
+    let mut presets = saved_presets(&app);
+    if presets.remove(&name).is_none() {
+        return Ok(false);
+    }
+    info!(target: "display", "Deleting preset '{}'", name);
+    store::set_value(&app, DISPLAY_PRESETS_KEY, &presets)?;
+    Ok(true)
+}
+
+/// Converts a saved window URL back into a webview URL
+fn preset_url(url: &str) -> Result<WebviewUrl, String> {
+    let url = url
+        .parse::<tauri::Url>()
+        .map_err(|e| format!("Invalid URL '{}': {}", url, e))?;
+    Ok(match url.scheme() {
+        "http" | "https" => WebviewUrl::External(url),
+        _ => WebviewUrl::CustomProtocol(url),
+    })
+}
+
+/// Opens a preset window, or points an already open one at the preset URL
+fn open_preset_window(
+    app: &AppHandle,
+    entry: &DisplayPresetWindow,
+) -> Result<WebviewWindow, String> {
+    if let Some(window) = app.get_webview_window(&entry.label) {
+        if window.url().ok().map(|url| url.to_string()).as_deref() != Some(entry.url.as_str()) {
+            let url = entry
+                .url
+                .parse::<tauri::Url>()
+                .map_err(|e| format!("Invalid URL '{}': {}", entry.url, e))?;
+            window
+                .navigate(url)
+                .map_err(|e| format!("Failed to navigate window: {}", e))?;
+        }
+        return Ok(window);
+    }
+
+    info!(target: "display", "Opening '{}' from preset", entry.label);
+    tauri::WebviewWindowBuilder::new(app, &entry.label, preset_url(&entry.url)?)
+        .title(&entry.title)
+        .visible(false)
+        .on_document_title_changed(|window, title| {
+            crate::webview::on_title_changed(window.as_ref(), title)
+        })
+        .build()
+        .map_err(|e| format!("Failed to create window: {}", e))
+}
+
+/// Opens or updates one preset window and moves it into place
+fn apply_preset_window(
+    app: &AppHandle,
+    state: &DisplayModeState,
+    entry: &DisplayPresetWindow,
+    monitors: &[tauri::Monitor],
+) -> Result<(), String> {
+    let window = open_preset_window(app, entry)?;
+
+    let origin = match &entry.monitor {
+        Some(name) => match monitors.iter().find(|m| m.name() == Some(name)) {
+            Some(monitor) => Some(*monitor.position()),
+            None => {
+                warn!(
+                    target: "display",
+                    "Monitor '{}' for '{}' is disconnected, leaving position as is",
+                    name, entry.label
+                );
+                None
+            }
+        },
+        None => Some(PhysicalPosition::new(0, 0)),
+    };
+
+    if window.is_fullscreen().unwrap_or(false) {
+        display::exit_fullscreen(&window, state)?;
+    }
+    if let Some(origin) = origin {
+        window
+            .set_position(PhysicalPosition::new(
+                origin.x + entry.x,
+                origin.y + entry.y,
+            ))
+            .map_err(|e| format!("Failed to set window position: {}", e))?;
+    }
+    window
+        .set_size(PhysicalSize::new(entry.width, entry.height))
+        .map_err(|e| format!("Failed to set window size: {}", e))?;
+    window
+        .show()
+        .map_err(|e| format!("Failed to show window: {}", e))?;
+    if entry.fullscreen {
+        display::enter_fullscreen(&window, state)?;
+    }
+    Ok(())
+}
+
+/// Opens the display windows of a preset (reusing windows that are already open),
+/// places them and assigns them to their monitors. Windows not in the preset are left open.
+/// Returns the labels of the windows applied.
+#[tauri::command]
+pub async fn apply_display_preset(
+    app: AppHandle,
+    state: tauri::State<'_, DisplayModeState>,
+    name: String,
+) -> Result<Vec<String>, String> {
+    let windows = saved_presets(&app)
+        .remove(&name)
+        .ok_or_else(|| format!("Display preset '{}' not found", name))?;
+    info!(target: "display", "Applying preset '{}'", name);
+
+    let monitors = app
+        .available_monitors()
+        .map_err(|e| format!("Failed to list monitors: {}", e))?;
+
+    let mut applied = Vec::new();
+    let mut errors = Vec::new();
+    for entry in &windows {
+        match apply_preset_window(&app, &state, entry, &monitors) {
+            Ok(()) => applied.push(entry.label.clone()),
+            Err(e) => {
+                warn!(target: "display", "Failed to apply '{}': {}", entry.label, e);
+                errors.push(format!("{}: {}", entry.label, e));
+            }
+        }
+    }
+
+    if !errors.is_empty() {
+        return Err(format!(
+            "Failed to apply display preset '{}': {}",
+            name,
+            errors.join("; ")
+        ));
+    }
+
+    // Keep monitor assignments in line with the preset so later monitor changes follow it.
+    // Only saved once every window is in place, so a failed apply leaves them as they were.
+    let mut assignments = display::monitor_assignments(&app);
+    for entry in &windows {
+        if let Some(monitor) = &entry.monitor {
+            assignments.insert(entry.label.clone(), monitor.clone());
+        }
+    }
+    store::set_value(&app, display::MONITOR_ASSIGNMENTS_KEY, &assignments)?;
+    Ok(applied)
+}
@@ -1,27 +1,134 @@
+use log::{error, info};
 use parking_lot::Mutex;
 use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
+use std::time::Duration;
 use tauri_plugin_shell::process::CommandChild;
 
+/// Maximum number of sidecar output lines kept in memory for diagnostics
+pub const SERVER_LOG_CAPACITY: usize = 200;
+
+/// Number of recent ping round-trips averaged by `ping_server`
+pub const SERVER_PING_SAMPLES: usize = 20;
+
 pub struct AppState {
     pub server: Arc<Mutex<Option<CommandChild>>>,
     pub server_port: u16,
+    pub server_logs: Arc<Mutex<VecDeque<String>>>,
+    /// Set while a restart is in progress to reject concurrent restarts
+    pub server_restarting: AtomicBool,
+    /// Why the sidecar couldn't be started (e.g. missing binary), if it failed
+    pub server_error: Mutex<Option<String>>,
+    /// Recent ping round-trips in milliseconds, for the rolling average
+    pub server_pings: Mutex<VecDeque<f64>>,
+    /// Set while the server is a sidecar we spawned. A server started elsewhere (e.g. by
+    /// `beforeDevCommand` in dev mode, or a shared dev backend) is never terminated.
+    pub server_managed: AtomicBool,
+}
+
+impl AppState {
+    /// Appends a sidecar output line, dropping the oldest once the buffer is full
+    pub fn push_server_log(&self, line: String) {
+        let mut logs = self.server_logs.lock();
+        if logs.len() >= SERVER_LOG_CAPACITY {
+            logs.pop_front();
+        }
+        logs.push_back(line);
+    }
+
+    /// Records a ping round-trip and returns the updated latency stats
+    pub fn record_server_ping(&self, latency_ms: f64) -> ServerPing {
+        let mut pings = self.server_pings.lock();
+        if pings.len() >= SERVER_PING_SAMPLES {
+            pings.pop_front();
+        }
+        pings.push_back(latency_ms);
+
+        ServerPing {
+            latency_ms,
+            average_ms: pings.iter().sum::<f64>() / pings.len() as f64,
+            samples: pings.len(),
+        }
+    }
 }
 
 impl Drop for AppState {
     fn drop(&mut self) {
+        if !self.server_managed.load(Ordering::SeqCst) {
+            return;
+        }
         if let Some(child) = self.server.lock().take() {
             if let Err(e) = child.kill() {
-                eprintln!("[sidecar] Failed to kill server on drop: {e}");
+                error!(target: "sidecar", "Failed to kill server on drop: {e}");
             } else {
-                println!("[sidecar] Server killed on AppState drop.");
+                info!(target: "sidecar", "Server killed on AppState drop.");
             }
         }
     }
 }
 
+/// Tracks the `before-exit` handshake that lets the frontend save before the app quits
+#[derive(Default)]
+pub struct ExitState {
+    /// Set once exit has started, so repeated close requests don't restart the handshake
+    pub exiting: AtomicBool,
+    /// Notified when the frontend acknowledges `before-exit`
+    pub acknowledged: tokio::sync::Notify,
+    /// Set while closing the main window is blocked (e.g. during a live service)
+    pub close_protected: AtomicBool,
+    /// Set by `force_close` to let the next close request through despite close protection
+    pub force_close: AtomicBool,
+}
+
 #[derive(Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ServerConfig {
     pub server_port: u16,
+    pub server_error: Option<String>,
+}
+
+/// CPU and memory usage of the sidecar process
+#[derive(Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ServerResourceUsage {
+    pub pid: u32,
+    /// Percent of a single core, so it can exceed 100 on multi-core machines
+    pub cpu_percent: f32,
+    pub memory_bytes: u64,
+}
+
+/// Round-trip latency to the sidecar's /ping endpoint
+#[derive(Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ServerPing {
+    pub latency_ms: f64,
+    /// Average over the last `samples` pings
+    pub average_ms: f64,
+    pub samples: usize,
+}
+
+#[derive(Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct StartupPhase {
+    pub phase: String,
+    pub millis: f64,
+}
+
+/// Timings of the startup phases, kept for the diagnostics bundle
+#[derive(Default)]
+pub struct StartupMetrics {
+    pub phases: Mutex<Vec<StartupPhase>>,
+}
+
+impl StartupMetrics {
+    /// Logs a startup phase duration and records it
+    pub fn record(&self, phase: &str, elapsed: Duration) {
+        info!(target: "startup", "{phase}: {elapsed:?}");
+        self.phases.lock().push(StartupPhase {
+            phase: phase.to_string(),
+            millis: elapsed.as_secs_f64() * 1000.0,
+        });
+    }
 }
@@ -0,0 +1,123 @@
+use serde::ser::SerializeStruct;
+use serde::{Serialize, Serializer};
+
+/// Serializes an error enum as `{ kind, message }` using its `kind()` and `Display`,
+/// so the frontend doesn't depend on message wording
+macro_rules! serialize_kind_message {
+    ($error:ident) => {
+        impl Serialize for $error {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                let mut state = serializer.serialize_struct(stringify!($error), 2)?;
+                state.serialize_field("kind", self.kind())?;
+                state.serialize_field("message", &self.to_string())?;
+                state.end()
+            }
+        }
+    };
+}
+
+/// Errors returned by the child webview commands
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum WebviewError {
+    #[error("Webview '{0}' not found")]
+    NotFound(String),
+
+    #[error("Invalid URL '{url}': {reason}")]
+    InvalidUrl { url: String, reason: String },
+
+    #[error("Webview '{0}' already exists")]
+    AlreadyExists(String),
+
+    #[error("Webview '{0}' is still being created")]
+    AlreadyCreating(String),
+
+    #[error("Timed out after {waited_ms}ms waiting for window '{label}'")]
+    Timeout { label: String, waited_ms: u64 },
+
+    #[error("{0}")]
+    PlatformError(String),
+}
+
+impl WebviewError {
+    /// Wraps a platform failure with context describing what was attempted
+    pub fn platform(context: &str, error: impl std::fmt::Display) -> Self {
+        WebviewError::PlatformError(format!("{context}: {error}"))
+    }
+
+    /// Stable identifier the frontend can match on instead of the message
+    pub fn kind(&self) -> &'static str {
+        match self {
+            WebviewError::NotFound(_) => "notFound",
+            WebviewError::InvalidUrl { .. } => "invalidUrl",
+            WebviewError::AlreadyExists(_) => "alreadyExists",
+            WebviewError::AlreadyCreating(_) => "alreadyCreating",
+            WebviewError::Timeout { .. } => "timeout",
+            WebviewError::PlatformError(_) => "platformError",
+        }
+    }
+}
+
+serialize_kind_message!(WebviewError);
+
+/// Errors returned when opening a URL in the system browser
+#[derive(Debug, thiserror::Error)]
+pub enum ExternalUrlError {
+    #[error("Invalid URL '{url}': {reason}")]
+    InvalidUrl { url: String, reason: String },
+
+    #[error("URL scheme '{scheme}' is not allowed")]
+    SchemeNotAllowed { scheme: String },
+
+    #[error("{0}")]
+    PlatformError(String),
+}
+
+impl ExternalUrlError {
+    /// Stable identifier the frontend can match on instead of the message
+    pub fn kind(&self) -> &'static str {
+        match self {
+            ExternalUrlError::InvalidUrl { .. } => "invalidUrl",
+            ExternalUrlError::SchemeNotAllowed { .. } => "schemeNotAllowed",
+            ExternalUrlError::PlatformError(_) => "platformError",
+        }
+    }
+}
+
+serialize_kind_message!(ExternalUrlError);
+
+/// Errors returned when checking a file before import
+#[derive(Debug, thiserror::Error)]
+pub enum ImportFileError {
+    #[error("File not found: {path}")]
+    NotFound { path: String },
+
+    #[error("File '{path}' can't be read: {reason}")]
+    Unreadable { path: String, reason: String },
+
+    #[error("Files of type '{extension}' can't be imported")]
+    UnsupportedFormat { extension: String },
+
+    #[error("File '{path}' is corrupt or not a valid {format} file: {reason}")]
+    Corrupt {
+        path: String,
+        format: String,
+        reason: String,
+    },
+}
+
+impl ImportFileError {
+    /// Stable identifier the frontend can match on instead of the message
+    pub fn kind(&self) -> &'static str {
+        match self {
+            ImportFileError::NotFound { .. } => "notFound",
+            ImportFileError::Unreadable { .. } => "unreadable",
+            ImportFileError::UnsupportedFormat { .. } => "unsupportedFormat",
+            ImportFileError::Corrupt { .. } => "corrupt",
+        }
+    }
+}
+
+serialize_kind_message!(ImportFileError);
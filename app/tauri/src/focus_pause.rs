@@ -0,0 +1,159 @@
+use crate::store;
+use crate::webview::{resume_child_webview, suspend_child_webview, WebviewRegistry};
+use log::{info, warn};
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+use tauri::{AppHandle, Emitter, Manager};
+
+/// Store key for the pause-on-blur settings
+pub const PAUSE_ON_BLUR_KEY: &str = "pauseOnBlur";
+
+/// Time allowed for focus to move to another app window before the app counts as blurred
+const FOCUS_SETTLE_DELAY: Duration = Duration::from_millis(150);
+
+/// State of the pause on blur
+#[derive(Default)]
+pub struct FocusPauseState {
+    /// Child webviews suspended by the current auto-pause, or None while not auto-paused
+    pub auto_paused: Mutex<Option<Vec<String>>>,
+}
+
+/// What happens when the app loses focus (off by default, since many setups run
+/// in the background on purpose)
+#[derive(Serialize, Deserialize, Clone, Copy, Default, Debug)]
+#[serde(rename_all = "camelCase", default)]
+pub struct PauseOnBlurSettings {
+    /// Ask the frontend to pause media while no app window is focused
+    pub enabled: bool,
+    /// Also suspend visible child webviews (e.g. embedded videos) until focus returns
+    pub suspend_webviews: bool,
+}
+
+/// Payload of the `auto-pause` event
+#[derive(Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AutoPause {
+    /// True when pausing on blur, false when resuming on focus
+    pub paused: bool,
+    pub suspended_webviews: Vec<String>,
+}
+
+/// Gets the pause-on-blur settings
+#[tauri::command]
+pub fn get_pause_on_blur(app: AppHandle) -> PauseOnBlurSettings {
+    store::get_value(&app, PAUSE_ON_BLUR_KEY).unwrap_or_default()
+}
+
+/// Sets the pause-on-blur settings
+#[tauri::command]
+pub fn set_pause_on_blur(app: AppHandle, settings: PauseOnBlurSettings) -> Result<(), String> {
+    info!(target: "focus", "Setting pause on blur: {settings:?}");
+    store::set_value(&app, PAUSE_ON_BLUR_KEY, &settings)
+}
+
+/// Suspends the visible child webviews, returning the labels suspended
+async fn suspend_visible_webviews(app: &AppHandle) -> Vec<String> {
+    let labels: Vec<String> = app
+        .webviews()
+        .into_values()
+        // Webview windows' own webviews share the window label; only children are suspended
+        .filter(|webview| webview.label() != webview.window().label())
+        .map(|webview| webview.label().to_string())
+        .filter(|label| !app.state::<WebviewRegistry>().is_hidden(label))
+        .collect();
+
+    let mut suspended = Vec::new();
+    for label in labels {
+        match suspend_child_webview(app.clone(), app.state(), label.clone()).await {
+            Ok(()) => suspended.push(label),
+            Err(e) => warn!(target: "focus", "Failed to suspend '{label}': {e}"),
+        }
+    }
+    suspended
+}
+
+/// Emits `auto-pause`
+fn emit_auto_pause(app: &AppHandle, payload: AutoPause) {
+    if let Err(e) = app.emit("auto-pause", payload) {
+        warn!(target: "focus", "Failed to emit auto-pause: {e}");
+    }
+}
+
+/// Pauses when no app window is focused any more, and resumes once one is again.
+/// Call on every window focus change.
+pub fn on_focus_changed(app: &AppHandle) {
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        // Focus moving between our own windows briefly leaves none focused
+        tokio::time::sleep(FOCUS_SETTLE_DELAY).await;
+        let focused = app
+            .webview_windows()
+            .values()
+            .any(|window| window.is_focused().unwrap_or(false));
+        let Some(state) = app.try_state::<FocusPauseState>() else {
+            return;
+        };
+
+        if focused {
+            let Some(suspended) = state.auto_paused.lock().take() else {
+                return;
+            };
+            info!(target: "focus", "App focused, resuming");
+            for label in &suspended {
+                let resumed = resume_child_webview(app.clone(), app.state(), label.clone()).await;
+                if let Err(e) = resumed {
+                    warn!(target: "focus", "Failed to resume '{label}': {e}");
+                }
+            }
+            emit_auto_pause(
+                &app,
+                AutoPause {
+                    paused: false,
+                    suspended_webviews: suspended,
+                },
+            );
+            return;
+        }
+
+        let settings = get_pause_on_blur(app.clone());
+        if !settings.enabled {
+            return;
+        }
+        {
+            let mut auto_paused = state.auto_paused.lock();
+            if auto_paused.is_some() {
+                return;
+            }
+            *auto_paused = Some(Vec::new());
+        }
+        info!(target: "focus", "App lost focus, pausing");
+
+        let suspended = if settings.suspend_webviews {
+            suspend_visible_webviews(&app).await
+        } else {
+            Vec::new()
+        };
+        let still_paused = match state.auto_paused.lock().as_mut() {
+            Some(auto_paused) => {
+                auto_paused.extend(suspended.iter().cloned());
+                true
+            }
+            None => false,
+        };
+        if !still_paused {
+            // Focus came back while suspending; the resume already ran, so undo here
+            for label in suspended {
+                let _ = resume_child_webview(app.clone(), app.state(), label).await;
+            }
+            return;
+        }
+        emit_auto_pause(
+            &app,
+            AutoPause {
+                paused: true,
+                suspended_webviews: suspended,
+            },
+        );
+    });
+}
@@ -0,0 +1,64 @@
+use crate::store;
+use log::info;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use tauri::{AppHandle, Emitter};
+
+/// Store key for the audio transport key bindings
+pub const TRANSPORT_BINDINGS_KEY: &str = "transportKeyBindings";
+
+/// Audio transport action triggered by a key, emitted to the frontend as `audio-transport`
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(tag = "action", rename_all = "camelCase")]
+pub enum TransportAction {
+    PlayPause,
+    SeekRelative { seconds: f64 },
+}
+
+/// Default bindings (J/K/L like most media players; arrow keys are left to slide navigation)
+pub fn default_transport_bindings() -> BTreeMap<String, TransportAction> {
+    BTreeMap::from([
+        (
+            "j".to_string(),
+            TransportAction::SeekRelative { seconds: -10.0 },
+        ),
+        ("k".to_string(), TransportAction::PlayPause),
+        (
+            "l".to_string(),
+            TransportAction::SeekRelative { seconds: 10.0 },
+        ),
+    ])
+}
+
+/// Normalizes a `KeyboardEvent.key` value so single characters match regardless of case
+fn normalize_key(key: &str) -> String {
+    if key.chars().count() == 1 {
+        key.to_lowercase()
+    } else {
+        key.to_string()
+    }
+}
+
+/// Gets the audio transport key bindings (`KeyboardEvent.key` -> action)
+#[tauri::command]
+pub fn get_transport_key_bindings(app: AppHandle) -> BTreeMap<String, TransportAction> {
+    store::get_value(&app, TRANSPORT_BINDINGS_KEY).unwrap_or_else(default_transport_bindings)
+}
+
+/// Replaces the audio transport key bindings (an empty map disables them)
+#[tauri::command]
+pub fn set_transport_key_bindings(
+    app: AppHandle,
+    bindings: BTreeMap<String, TransportAction>,
+) -> Result<(), String> {
+    let bindings: BTreeMap<String, TransportAction> = bindings
+        .into_iter()
+        .filter(|(key, _)| !key.trim().is_empty())
+        .map(|(key, action)| (normalize_key(&key), action))
+        .collect();
+    info!(target: "keyboard", "Setting transport key bindings: {bindings:?}");
+
+    store::set_value(&app, TRANSPORT_BINDINGS_KEY, &bindings)?;
+    app.emit("transport-key-bindings-changed", &bindings)
+        .map_err(|e| format!("Failed to emit transport-key-bindings-changed: {e}"))
+}
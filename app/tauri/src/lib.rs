@@ -1,39 +1,199 @@
 pub mod commands;
 pub mod domain;
+pub mod error;
+pub mod logging;
+pub mod store;
 
 // Desktop-only modules
 #[cfg(desktop)]
+pub mod cues;
+#[cfg(desktop)]
+pub mod deep_link;
+#[cfg(desktop)]
+pub mod diagnostics;
+#[cfg(desktop)]
+pub mod display;
+#[cfg(desktop)]
+pub mod display_mirror;
+#[cfg(desktop)]
+pub mod display_preset;
+#[cfg(desktop)]
+pub mod focus_pause;
+#[cfg(desktop)]
+pub mod keyboard;
+#[cfg(desktop)]
+pub mod reset;
+#[cfg(desktop)]
+pub mod self_test;
+#[cfg(desktop)]
 pub mod server;
 #[cfg(desktop)]
+pub mod shortcuts;
+#[cfg(desktop)]
+pub mod show_state;
+#[cfg(desktop)]
+pub mod splash;
+#[cfg(desktop)]
+pub mod support_bundle;
+#[cfg(desktop)]
+pub mod url_import;
+#[cfg(desktop)]
+pub mod volume;
+#[cfg(desktop)]
 pub mod webview;
+#[cfg(desktop)]
+pub mod webview2;
+#[cfg(desktop)]
+pub mod webview_recovery;
+#[cfg(desktop)]
+pub mod window;
 
-use commands::{clear_pending_import, get_pending_import, get_server_config};
+use commands::{
+    clear_pending_import, get_last_route, get_media_root, get_pending_import, get_server_config,
+    open_external_url, save_last_route, set_media_root, validate_import_file,
+};
+#[cfg(desktop)]
+use commands::{forward_file_import, is_importable_file, PendingImport};
+#[cfg(desktop)]
+use commands::{
+    acknowledge_exit, force_close, get_close_protection, get_disk_space, get_server_resource_usage,
+    get_sidecar_environment, is_devtools_open, kill_orphaned_sidecars, list_orphaned_sidecars,
+    ping_server, reset_zoom, restart_server, restart_server_if_unhealthy, reveal_in_file_manager,
+    set_close_protection, set_sidecar_environment, toggle_devtools, zoom_in, zoom_out, ZoomState,
+};
+#[cfg(desktop)]
+use cues::{
+    delete_cue_recording, get_cue_recording, list_cue_recordings, replay_cues, start_cue_recording,
+    stop_cue_recording, stop_cue_replay, CueState,
+};
+#[cfg(desktop)]
+use deep_link::{get_pending_deep_link, handle_deep_link, is_deep_link, PendingDeepLink};
+#[cfg(desktop)]
+use diagnostics::{collect_diagnostics, dump_webview_tree, get_component_versions};
+#[cfg(desktop)]
+use display::{
+    capture_display, focus_display_window, get_display_assignments, get_display_color_info,
+    init_monitor_assignments, is_display_window, list_display_windows, list_monitors,
+    set_display_fullscreen, set_display_kiosk, set_display_monitor, set_display_title,
+    watch_monitors, DisplayModeState,
+};
+#[cfg(desktop)]
+use display_mirror::{list_display_mirrors, mirror_display, unmirror_display, MirrorState};
 #[cfg(desktop)]
-use commands::PendingImport;
+use display_preset::{
+    apply_display_preset, delete_display_preset, list_display_presets, save_display_preset,
+};
+#[cfg(desktop)]
+use focus_pause::{get_pause_on_blur, set_pause_on_blur, FocusPauseState};
 #[cfg(desktop)]
-use commands::{reset_zoom, restart_server, toggle_devtools, zoom_in, zoom_out, ZoomState};
+use keyboard::{get_transport_key_bindings, set_transport_key_bindings};
+#[cfg(desktop)]
+use reset::{reset_app_state, ResetState};
 #[cfg(all(desktop, not(debug_assertions)))]
 use server::{get_port_process_info, is_port_in_use, kill_port_process};
 #[cfg(desktop)]
+use self_test::run_self_test;
+#[cfg(desktop)]
+use shortcuts::{get_shortcuts, register_saved_shortcuts, set_shortcut};
+#[cfg(desktop)]
+use show_state::{export_show_state, import_show_state};
+#[cfg(desktop)]
+use splash::{get_startup_splash, set_startup_splash};
+#[cfg(desktop)]
+use support_bundle::export_support_bundle;
+#[cfg(desktop)]
+use volume::{get_system_volume, get_volume_limit, set_system_volume, set_volume_limit};
+#[cfg(desktop)]
 use webview::{
-    close_child_webview, create_child_webview, hide_child_webview, show_child_webview,
-    update_child_webview, webview_exists,
+    close_child_webview, configure_child_webview, create_child_webview, ensure_display,
+    get_default_webview_options, hide_child_webview, is_child_webview_suspended,
+    resume_child_webview, set_default_webview_options, show_child_webview, suspend_child_webview,
+    update_child_webview, webview_exists, webview_post_message, webview_send_message,
+    WebviewRegistry,
+};
+#[cfg(desktop)]
+use webview2::{
+    get_hardware_acceleration, get_webview2_arguments, set_hardware_acceleration,
+    set_webview2_arguments,
+};
+#[cfg(desktop)]
+use webview_recovery::{
+    get_webview_auto_reload, set_webview_auto_reload, watch_network_recovery, WebviewRecoveryState,
+};
+#[cfg(desktop)]
+use window::{
+    get_always_on_top, get_window_decorations, get_window_role, get_window_title,
+    set_always_on_top, set_window_decorations, set_window_role, set_window_title,
 };
 #[cfg(desktop)]
-use domain::AppState;
+use domain::{AppState, ExitState};
+use domain::StartupMetrics;
 #[cfg(desktop)]
 use parking_lot::Mutex;
 #[cfg(desktop)]
 use std::path::PathBuf;
-#[cfg(desktop)]
 use std::sync::Arc;
 use std::time::Instant;
-use tauri::Emitter;
+use log::info;
 use tauri::RunEvent;
-#[cfg(desktop)]
 use tauri::Manager;
 #[cfg(desktop)]
 use tauri::WindowEvent;
+#[cfg(desktop)]
+use tauri::Emitter;
+#[cfg(desktop)]
+use std::sync::atomic::Ordering;
+#[cfg(desktop)]
+use std::time::Duration;
+
+/// How long the frontend gets to acknowledge `before-exit` before the app quits anyway
+#[cfg(desktop)]
+const BEFORE_EXIT_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Closes all display windows and custom-page webviews, then exits the app
+#[cfg(desktop)]
+fn close_children_and_exit(app_handle: &tauri::AppHandle) {
+    // Get all webview windows
+    let windows = app_handle.webview_windows();
+
+    // Close all display windows and custom-page webviews
+    let child_windows: Vec<_> = windows
+        .into_iter()
+        .filter(|(label, _)| is_display_window(label) || label.starts_with("custom-page-"))
+        .collect();
+
+    info!(
+        target: "window-event",
+        "Closing {} child windows/webviews",
+        child_windows.len()
+    );
+
+    for (label, win) in child_windows {
+        info!(target: "window-event", "Closing: {label}");
+        if let Err(e) = win.close() {
+            log::warn!(target: "window-event", "Failed to close {label}: {e}");
+        }
+    }
+
+    // Also close any child webviews
+    let webviews = app_handle.webviews();
+    let custom_webviews: Vec<_> = webviews
+        .into_iter()
+        .filter(|(label, _)| label.starts_with("custom-page-"))
+        .collect();
+
+    for (label, wv) in custom_webviews {
+        info!(target: "window-event", "Closing webview: {label}");
+        if let Err(e) = wv.close() {
+            log::warn!(target: "window-event", "Failed to close webview {label}: {e}");
+        }
+    }
+
+    // Exit the application immediately - Tauri handles cleanup gracefully
+    // No need for blocking sleep which would freeze the main thread
+    info!(target: "window-event", "Exiting application");
+    app_handle.exit(0);
+}
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
@@ -54,19 +214,23 @@ pub fn run() {
         scope.set_tag("platform", std::env::consts::OS);
     });
 
+    // Levels are configured with CHURCH_HUB_LOG (e.g. "info,webview=debug")
+    logging::init();
+
     let app_start = Instant::now();
-    println!("[startup] === Tauri Starting ===");
+    let startup_metrics = Arc::new(StartupMetrics::default());
+    info!(target: "startup", "=== Tauri Starting ===");
+
+    let context = tauri::generate_context!();
 
     // Enable GPU acceleration on Windows by ignoring the GPU blocklist
     // This ensures hardware-accelerated rendering for video playback (e.g., YouTube)
+    // Can be turned off for machines where the GPU flags corrupt video
     #[cfg(target_os = "windows")]
     {
         let t = Instant::now();
-        std::env::set_var(
-            "WEBVIEW2_ADDITIONAL_BROWSER_ARGUMENTS",
-            "--ignore-gpu-blocklist --enable-gpu-rasterization --enable-accelerated-video-decode",
-        );
-        println!("[startup] gpu_config: {:?}", t.elapsed());
+        webview2::apply_browser_arguments(&context.config().identifier);
+        startup_metrics.record("gpu_config", t.elapsed());
     }
 
     let builder_start = Instant::now();
@@ -75,7 +239,7 @@ pub fn run() {
     let t = Instant::now();
     let builder = tauri::Builder::default()
         .plugin(tauri_plugin_shell::init());  // Needed for sidecar
-    println!("[startup] plugin_shell: {:?}", t.elapsed());
+    startup_metrics.record("plugin_shell", t.elapsed());
 
     let t = Instant::now();
     let builder = builder
@@ -88,14 +252,14 @@ pub fn run() {
         .plugin(tauri_plugin_screen_brightness::init())
         .plugin(tauri_plugin_process::init())
         .plugin(tauri_plugin_libmpv::init());
-    println!("[startup] plugins_core: {:?}", t.elapsed());
+    startup_metrics.record("plugins_core", t.elapsed());
 
     // Global shortcut plugin is desktop-only
     #[cfg(desktop)]
     let builder = {
         let t = Instant::now();
         let b = builder.plugin(tauri_plugin_global_shortcut::Builder::new().build());
-        println!("[startup] plugin_shortcut: {:?}", t.elapsed());
+        startup_metrics.record("plugin_shortcut", t.elapsed());
         b
     };
 
@@ -113,9 +277,10 @@ pub fn run() {
                         | tauri_plugin_window_state::StateFlags::DECORATIONS
                         | tauri_plugin_window_state::StateFlags::VISIBLE,
                 )
+                .with_denylist(&[splash::SPLASH_WINDOW_LABEL])
                 .build(),
         );
-        println!("[startup] plugin_window_state: {:?}", t.elapsed());
+        startup_metrics.record("plugin_window_state", t.elapsed());
         b
     };
 
@@ -123,99 +288,132 @@ pub fn run() {
     #[cfg(desktop)]
     let builder = {
         let t = Instant::now();
-        let b = builder.plugin(tauri_plugin_single_instance::init(|app, args, _cwd| {
-            println!("[single-instance] Second instance launched with args: {args:?}");
+        let b = builder.plugin(tauri_plugin_single_instance::init(|app, args, cwd| {
+            info!(target: "single-instance", "Second instance launched with args: {args:?}");
 
-            // Check if any argument is a file we handle
+            // Forward every file we handle to this instance (skip first arg, the exe path).
+            // Relative paths are resolved against the second instance's working directory.
+            let mut forwarded = false;
             for arg in args.iter().skip(1) {
-                // Skip first arg (exe path)
-                let path = PathBuf::from(arg);
-                if path.extension().is_some_and(|ext| {
-                    ext.eq_ignore_ascii_case("pptx")
-                        || ext.eq_ignore_ascii_case("opensong")
-                        || ext.eq_ignore_ascii_case("churchprogram")
-                }) {
-                    println!("[single-instance] File detected: {path:?}");
-
-                    // Emit event to frontend so it can import the file
-                    if let Err(e) = app.emit("file-opened", path.to_string_lossy().to_string()) {
-                        println!("[single-instance] Failed to emit file-opened: {e}");
-                    }
+                if handle_deep_link(app, arg) {
+                    info!(target: "single-instance", "Link detected: {arg}");
+                    forwarded = true;
+                    continue;
+                }
 
-                    // Focus the main window
-                    if let Some(window) = app.get_webview_window("main") {
-                        // Unminimize if minimized
-                        let _ = window.unminimize();
-                        // Bring to front and focus
-                        let _ = window.set_focus();
-                    }
+                let path = PathBuf::from(arg);
+                if is_importable_file(&path) {
+                    info!(target: "single-instance", "File detected: {path:?}");
+                    let path = if path.is_relative() {
+                        PathBuf::from(&cwd).join(path)
+                    } else {
+                        path
+                    };
+                    forward_file_import(app, path);
+                    forwarded = true;
+                }
+            }
 
-                    break; // Only handle first file
+            // No file to import - still bring the existing window to front
+            if !forwarded {
+                if let Some(window) = app.get_webview_window("main") {
+                    let _ = window.unminimize();
+                    let _ = window.set_focus();
                 }
             }
         }));
-        println!("[startup] plugin_single_instance: {:?}", t.elapsed());
+        startup_metrics.record("plugin_single_instance", t.elapsed());
         b
     };
 
     // Window event handling is desktop-only (close child windows on main close)
     #[cfg(desktop)]
     let builder = builder.on_window_event(|window, event| {
+        if let WindowEvent::Focused(_) = event {
+            focus_pause::on_focus_changed(window.app_handle());
+        }
+
         // When the main window is closed, close all display windows and exit
-        if let WindowEvent::CloseRequested { .. } = event {
+        if let WindowEvent::CloseRequested { api, .. } = event {
+            // Display windows in kiosk mode can't be closed (e.g. Alt+F4) until kiosk is exited
+            if let Some(display_mode) = window.try_state::<DisplayModeState>() {
+                if display_mode.is_kiosk(window.label()) {
+                    info!(
+                        target: "window-event",
+                        "Blocked close of kiosk window: {}",
+                        window.label()
+                    );
+                    api.prevent_close();
+                    return;
+                }
+            }
+
             if window.label() == "main" {
-                println!("[window-event] Main window close requested");
-
-                // Get all webview windows
-                let app_handle = window.app_handle();
-                let windows = app_handle.webview_windows();
-
-                // Close all display windows and custom-page webviews
-                let child_windows: Vec<_> = windows
-                    .into_iter()
-                    .filter(|(label, _)| {
-                        label.starts_with("display-") || label.starts_with("custom-page-")
-                    })
-                    .collect();
-
-                println!(
-                    "[window-event] Closing {} child windows/webviews",
-                    child_windows.len()
-                );
-
-                for (label, win) in child_windows {
-                    println!("[window-event] Closing: {label}");
-                    if let Err(e) = win.close() {
-                        println!("[window-event] Failed to close {label}: {e}");
+                info!(target: "window-event", "Main window close requested");
+
+                // Close protection refuses the close until the operator confirms with
+                // force_close, which lets exactly one close request through
+                if let Some(exit_state) = window.try_state::<ExitState>() {
+                    if exit_state.close_protected.load(Ordering::SeqCst)
+                        && !exit_state.force_close.swap(false, Ordering::SeqCst)
+                    {
+                        info!(target: "window-event", "Blocked close of main window");
+                        api.prevent_close();
+                        if let Err(e) = window.emit_to("main", "close-blocked", ()) {
+                            log::warn!(target: "window-event", "Failed to emit close-blocked: {e}");
+                        }
+                        return;
                     }
                 }
 
-                // Also close any child webviews
-                let webviews = app_handle.webviews();
-                let custom_webviews: Vec<_> = webviews
-                    .into_iter()
-                    .filter(|(label, _)| label.starts_with("custom-page-"))
-                    .collect();
-
-                for (label, wv) in custom_webviews {
-                    println!("[window-event] Closing webview: {label}");
-                    if let Err(e) = wv.close() {
-                        println!("[window-event] Failed to close webview {label}: {e}");
+                // Give the frontend a chance to save before anything is closed
+                api.prevent_close();
+                let app_handle = window.app_handle().clone();
+                let already_exiting = match app_handle.try_state::<ExitState>() {
+                    Some(exit_state) => exit_state.exiting.swap(true, Ordering::SeqCst),
+                    None => {
+                        close_children_and_exit(&app_handle);
+                        return;
                     }
+                };
+                if already_exiting {
+                    info!(target: "window-event", "Exit already in progress");
+                    return;
+                }
+
+                if let Err(e) = window.emit_to("main", "before-exit", ()) {
+                    log::warn!(target: "window-event", "Failed to emit before-exit: {e}");
+                    close_children_and_exit(&app_handle);
+                    return;
                 }
 
-                // Exit the application immediately - Tauri handles cleanup gracefully
-                // No need for blocking sleep which would freeze the main thread
-                println!("[window-event] Exiting application");
-                app_handle.exit(0);
+                tauri::async_runtime::spawn(async move {
+                    let exit_state = app_handle.state::<ExitState>();
+                    // Hard timeout so a hung frontend can't block exit forever
+                    let acknowledged = exit_state.acknowledged.notified();
+                    if tokio::time::timeout(BEFORE_EXIT_TIMEOUT, acknowledged)
+                        .await
+                        .is_err()
+                    {
+                        log::warn!(
+                            target: "window-event",
+                            "No before-exit acknowledgement after {:?}, exiting anyway",
+                            BEFORE_EXIT_TIMEOUT
+                        );
+                    }
+                    close_children_and_exit(&app_handle);
+                });
             }
         }
     });
 
     // Desktop setup hook
     #[cfg(desktop)]
+    let setup_metrics = startup_metrics.clone();
+    #[cfg(desktop)]
     let builder = builder.setup(move |app| {
-        println!("[startup] tauri_builder: {:?}", builder_start.elapsed());
+        let startup_metrics = setup_metrics;
+        startup_metrics.record("tauri_builder", builder_start.elapsed());
         let setup_start = Instant::now();
 
         let server_port: u16 = 3000;
@@ -224,46 +422,92 @@ pub fn run() {
         let app_state = AppState {
             server: Arc::new(Mutex::new(None)),
             server_port,
+            server_logs: Arc::new(Mutex::new(std::collections::VecDeque::new())),
+            server_restarting: std::sync::atomic::AtomicBool::new(false),
+            server_error: Mutex::new(None),
+            server_pings: Mutex::new(std::collections::VecDeque::new()),
+            server_managed: std::sync::atomic::AtomicBool::new(false),
         };
         app.manage(app_state);
+        app.manage(startup_metrics.clone());
 
         // Initialize zoom state for tracking zoom levels per webview
         let zoom_state = ZoomState {
             zoom_levels: Mutex::new(std::collections::HashMap::new()),
         };
         app.manage(zoom_state);
-        println!("[startup] setup_app_state: {:?}", t.elapsed());
+
+        // Track fullscreen/kiosk display windows
+        app.manage(DisplayModeState::default());
+        init_monitor_assignments(app.handle());
+        watch_monitors(app.handle());
+        // Reload opted-in child webviews when their content is reachable again
+        app.manage(WebviewRecoveryState::default());
+        watch_network_recovery(app.handle());
+        // Global shortcuts the user bound with set_shortcut
+        register_saved_shortcuts(app.handle());
+        app.manage(ExitState::default());
+        // Child webview bookkeeping, cue recording, display mirrors, pause on blur, resets
+        app.manage(CueState::default());
+        app.manage(MirrorState::default());
+        app.manage(FocusPauseState::default());
+        app.manage(WebviewRegistry::default());
+        app.manage(ResetState::default());
+        startup_metrics.record("setup_app_state", t.elapsed());
 
         // Handle file association - check CLI args for PPTX file
         let t = Instant::now();
         let pending_import = PendingImport {
-            file_path: Mutex::new(None),
+            file_paths: Mutex::new(std::collections::VecDeque::new()),
         };
 
-        let args: Vec<String> = std::env::args().collect();
-        if args.len() > 1 {
-            let path = PathBuf::from(&args[1]);
-            if path.extension().is_some_and(|ext| {
-                ext.eq_ignore_ascii_case("pptx")
-                    || ext.eq_ignore_ascii_case("opensong")
-                    || ext.eq_ignore_ascii_case("churchprogram")
-            }) {
-                println!("[file-association] File detected: {path:?}");
-                *pending_import.file_path.lock() = Some(path);
+        let mut links = Vec::new();
+        for arg in std::env::args().skip(1) {
+            if is_deep_link(&arg) {
+                info!(target: "deep-link", "Link detected: {arg}");
+                links.push(arg);
+                continue;
+            }
+
+            let path = PathBuf::from(arg);
+            if is_importable_file(&path) {
+                info!(target: "file-association", "File detected: {path:?}");
+                pending_import.file_paths.lock().push_back(path);
             }
         }
 
         app.manage(pending_import);
-        println!("[startup] setup_file_association: {:?}", t.elapsed());
+        app.manage(PendingDeepLink::default());
+
+        // Links are queued for the frontend (linked files once downloaded)
+        for link in links {
+            handle_deep_link(app.handle(), &link);
+        }
+        startup_metrics.record("setup_file_association", t.elapsed());
 
         // In dev mode, the server is started by beforeDevCommand, so skip sidecar
         // In release mode, start the sidecar server
         #[cfg(not(debug_assertions))]
         {
+            // Reap sidecars left behind by a crash so they don't hold the port
+            let t = Instant::now();
+            match server::kill_orphaned_sidecars(app.handle()) {
+                Ok(killed) if !killed.is_empty() => {
+                    info!(target: "sidecar", "Killed {} orphaned sidecars", killed.len());
+                    // Wait a bit for the port to be released
+                    std::thread::sleep(std::time::Duration::from_millis(500));
+                }
+                Ok(_) => {}
+                Err(e) => {
+                    log::warn!(target: "sidecar", "Failed to check for orphaned sidecars: {e}")
+                }
+            }
+            startup_metrics.record("orphaned_sidecar_check", t.elapsed());
+
             // Check if port is already in use
             let t = Instant::now();
             if is_port_in_use(server_port) {
-                println!("[port-conflict] Port {} is already in use!", server_port);
+                log::warn!(target: "port-conflict", "Port {} is already in use!", server_port);
 
                 let process_info = get_port_process_info(server_port);
                 let message = if let Some(ref info) = process_info {
@@ -291,15 +535,23 @@ pub fn run() {
                     .blocking_show();
 
                 if should_kill {
-                    println!("[port-conflict] User chose to terminate the process");
+                    info!(target: "port-conflict", "User chose to terminate the process");
                     match kill_port_process(server_port) {
                         Ok(_) => {
-                            println!("[port-conflict] Successfully terminated process on port {}", server_port);
+                            info!(
+                                target: "port-conflict",
+                                "Successfully terminated process on port {}",
+                                server_port
+                            );
                             // Wait a bit for the port to be released
                             std::thread::sleep(std::time::Duration::from_millis(500));
                         }
                         Err(e) => {
-                            println!("[port-conflict] Failed to terminate process: {}", e);
+                            log::warn!(
+                                target: "port-conflict",
+                                "Failed to terminate process: {}",
+                                e
+                            );
                             // Show error dialog and exit
                             app.dialog()
                                 .message(format!("Failed to terminate the process: {}\n\nPlease manually close the application using port {} and try again.", e, server_port))
@@ -310,36 +562,47 @@ pub fn run() {
                         }
                     }
                 } else {
-                    println!("[port-conflict] User cancelled - exiting");
+                    info!(target: "port-conflict", "User cancelled - exiting");
                     std::process::exit(0);
                 }
             }
-            println!("[startup] port_conflict_check: {:?}", t.elapsed());
+            startup_metrics.record("port_conflict_check", t.elapsed());
 
             // Start the sidecar server
             let t = Instant::now();
-            if let Err(err) = server::start_server(app.handle(), server_port) {
-                println!("[sidecar] Failed to start the server: {err}");
-            }
-            println!("[startup] sidecar_spawn: {:?}", t.elapsed());
-
-            // Wait for server to be ready before showing UI
-            let t = Instant::now();
-            if let Err(err) = server::wait_for_server_ready(server_port, 30) {
-                println!("[sidecar] {err}");
+            let server_started = match server::start_server(app.handle(), server_port) {
+                Ok(()) => true,
+                Err(err) => {
+                    log::error!(target: "sidecar", "Failed to start the server: {err}");
+                    false
+                }
+            };
+            startup_metrics.record("sidecar_spawn", t.elapsed());
+
+            // Wait for server to be ready before showing UI (pointless if it never spawned),
+            // either behind a splash window or by blocking startup
+            let splash_shown = server_started
+                && splash::is_startup_splash_enabled(app.handle())
+                && splash::wait_for_server_with_splash(app.handle(), server_port);
+            if server_started && !splash_shown {
+                let t = Instant::now();
+                let ready = server::wait_for_server_startup(app.handle(), server_port, 30);
+                if let Err(err) = tauri::async_runtime::block_on(ready) {
+                    log::error!(target: "sidecar", "{err}");
+                }
+                startup_metrics.record("server_ready_wait", t.elapsed());
             }
-            println!("[startup] server_ready_wait: {:?}", t.elapsed());
         }
 
         #[cfg(debug_assertions)]
         {
-            println!("[dev] Skipping sidecar - using dev server from beforeDevCommand");
+            info!(target: "dev", "Skipping sidecar - using dev server from beforeDevCommand");
             // Wait for dev server to be ready
             let t = Instant::now();
             if let Err(err) = server::wait_for_server_ready(server_port, 30) {
-                println!("[dev] {err}");
+                log::warn!(target: "dev", "{err}");
             }
-            println!("[startup] dev_server_ready_wait: {:?}", t.elapsed());
+            startup_metrics.record("dev_server_ready_wait", t.elapsed());
         }
 
         // Inject keyboard shortcut handler into main webview
@@ -350,10 +613,34 @@ pub fn run() {
                     if (window.__tauriKeyboardHandlerInstalled) return;
                     window.__tauriKeyboardHandlerInstalled = true;
 
+                    // Audio transport bindings (KeyboardEvent.key -> action), configurable natively
+                    let transportBindings = {};
+                    window.__TAURI__.core.invoke('get_transport_key_bindings')
+                        .then((bindings) => { transportBindings = bindings; })
+                        .catch((err) => console.error('Failed to load transport key bindings:', err));
+                    window.__TAURI__.event.listen('transport-key-bindings-changed', (event) => {
+                        transportBindings = event.payload;
+                    });
+
+                    const isEditable = (target) =>
+                        target instanceof HTMLElement &&
+                        (target.isContentEditable || ['INPUT', 'TEXTAREA', 'SELECT'].includes(target.tagName));
+
                     document.addEventListener('keydown', async (e) => {
                         const isMac = navigator.platform.toUpperCase().indexOf('MAC') >= 0;
                         const ctrlOrCmd = isMac ? e.metaKey : e.ctrlKey;
 
+                        // Audio transport keys (not while typing, not with modifiers)
+                        if (!e.ctrlKey && !e.metaKey && !e.altKey && !isEditable(e.target)) {
+                            const key = e.key.length === 1 ? e.key.toLowerCase() : e.key;
+                            const binding = transportBindings[key];
+                            if (binding) {
+                                e.preventDefault();
+                                window.__TAURI__.event.emit('audio-transport', binding);
+                                return;
+                            }
+                        }
+
                         // F12 or Ctrl+Shift+I: Toggle DevTools
                         if (e.key === 'F12' || (ctrlOrCmd && e.shiftKey && e.key === 'I')) {
                             e.preventDefault();
@@ -406,7 +693,7 @@ pub fn run() {
                         }
                     });
 
-                    console.log('[tauri] Keyboard handler installed: F1-F11 (prevented browser default), F12/Ctrl+Shift+I (DevTools), Ctrl+/-/0 (Zoom)');
+                    console.log('[tauri] Keyboard handler installed: F1-F11 (prevented browser default), F12/Ctrl+Shift+I (DevTools), Ctrl+/-/0 (Zoom), audio transport keys');
                 })();
             "#;
 
@@ -417,65 +704,173 @@ pub fn run() {
                 std::thread::sleep(std::time::Duration::from_millis(500));
                 if let Some(wv) = handle.webview_windows().get("main") {
                     if let Err(e) = wv.eval(keyboard_handler) {
-                        println!("[keyboard] Failed to inject keyboard handler: {e}");
+                        log::warn!(target: "keyboard", "Failed to inject keyboard handler: {e}");
                     } else {
-                        println!("[keyboard] Keyboard shortcuts installed");
+                        info!(target: "keyboard", "Keyboard shortcuts installed");
                     }
                 }
             });
         }
-        println!("[startup] keyboard_handler_setup: {:?}", t.elapsed());
+        startup_metrics.record("keyboard_handler_setup", t.elapsed());
 
-        println!("[startup] setup_hook_total: {:?}", setup_start.elapsed());
-        println!("[startup] === Tauri Ready (total: {:?}) ===", app_start.elapsed());
+        startup_metrics.record("setup_hook_total", setup_start.elapsed());
+        info!(target: "startup", "=== Tauri Ready (total: {:?}) ===", app_start.elapsed());
 
         Ok(())
     });
 
     // Mobile setup hook (simplified - no sidecar, no file association)
     #[cfg(mobile)]
-    let builder = builder.setup(move |_app| {
-        println!("[startup] tauri_builder: {:?}", builder_start.elapsed());
-        println!("[mobile] Mobile mode - server connection configured by user");
-        println!("[startup] === Tauri Ready (total: {:?}) ===", app_start.elapsed());
+    let setup_metrics = startup_metrics.clone();
+    #[cfg(mobile)]
+    let builder = builder.setup(move |app| {
+        let startup_metrics = setup_metrics;
+        startup_metrics.record("tauri_builder", builder_start.elapsed());
+        app.manage(startup_metrics.clone());
+        info!(target: "mobile", "Mobile mode - server connection configured by user");
+        info!(target: "startup", "=== Tauri Ready (total: {:?}) ===", app_start.elapsed());
         Ok(())
     });
 
     // Desktop: include all commands including webview management
     #[cfg(desktop)]
-    let builder = builder.invoke_handler(tauri::generate_handler![
+    let handler: fn(tauri::ipc::Invoke) -> bool = tauri::generate_handler![
         get_server_config,
         get_pending_import,
         clear_pending_import,
+        validate_import_file,
+        get_pending_deep_link,
+        save_last_route,
+        get_last_route,
+        set_media_root,
+        get_media_root,
+        open_external_url,
+        reveal_in_file_manager,
         create_child_webview,
+        ensure_display,
         close_child_webview,
         show_child_webview,
         hide_child_webview,
+        suspend_child_webview,
+        resume_child_webview,
+        is_child_webview_suspended,
         update_child_webview,
+        configure_child_webview,
+        get_default_webview_options,
+        set_default_webview_options,
         webview_exists,
+        webview_post_message,
+        webview_send_message,
+        set_webview_auto_reload,
+        get_webview_auto_reload,
+        get_hardware_acceleration,
+        set_hardware_acceleration,
+        get_webview2_arguments,
+        set_webview2_arguments,
         toggle_devtools,
+        is_devtools_open,
         zoom_in,
         zoom_out,
         reset_zoom,
-        restart_server
-    ]);
+        get_transport_key_bindings,
+        set_transport_key_bindings,
+        get_pause_on_blur,
+        set_pause_on_blur,
+        get_shortcuts,
+        set_shortcut,
+        get_system_volume,
+        set_system_volume,
+        get_volume_limit,
+        set_volume_limit,
+        restart_server,
+        restart_server_if_unhealthy,
+        get_server_resource_usage,
+        get_disk_space,
+        list_orphaned_sidecars,
+        kill_orphaned_sidecars,
+        ping_server,
+        get_sidecar_environment,
+        set_sidecar_environment,
+        reset_app_state,
+        export_show_state,
+        import_show_state,
+        acknowledge_exit,
+        set_close_protection,
+        get_close_protection,
+        force_close,
+        collect_diagnostics,
+        dump_webview_tree,
+        get_component_versions,
+        export_support_bundle,
+        run_self_test,
+        get_startup_splash,
+        set_startup_splash,
+        list_display_windows,
+        list_monitors,
+        set_display_monitor,
+        get_display_assignments,
+        get_display_color_info,
+        save_display_preset,
+        list_display_presets,
+        delete_display_preset,
+        apply_display_preset,
+        mirror_display,
+        unmirror_display,
+        list_display_mirrors,
+        focus_display_window,
+        set_display_fullscreen,
+        set_display_kiosk,
+        set_display_title,
+        set_always_on_top,
+        get_always_on_top,
+        set_window_title,
+        get_window_title,
+        set_window_decorations,
+        get_window_decorations,
+        set_window_role,
+        get_window_role,
+        capture_display,
+        start_cue_recording,
+        stop_cue_recording,
+        list_cue_recordings,
+        get_cue_recording,
+        delete_cue_recording,
+        replay_cues,
+        stop_cue_replay
+    ];
+    #[cfg(desktop)]
+    let builder = builder.invoke_handler(move |invoke| {
+        // Capture display/webview cues for rehearsal recordings
+        cues::record_invoke(
+            invoke.message.webview_ref().app_handle(),
+            invoke.message.command(),
+            invoke.message.payload(),
+        );
+        handler(invoke)
+    });
 
     // Mobile: only basic commands (no webview management)
     #[cfg(mobile)]
     let builder = builder.invoke_handler(tauri::generate_handler![
         get_server_config,
         get_pending_import,
-        clear_pending_import
+        clear_pending_import,
+        validate_import_file,
+        save_last_route,
+        get_last_route,
+        set_media_root,
+        get_media_root,
+        open_external_url
     ]);
 
-    println!("[startup] builder_chain_setup: {:?}", builder_start.elapsed());
+    startup_metrics.record("builder_chain_setup", builder_start.elapsed());
     let build_start = Instant::now();
 
     let app = builder
-        .build(tauri::generate_context!())
+        .build(context)
         .expect("error while running tauri application");
 
-    println!("[startup] tauri_build: {:?}", build_start.elapsed());
+    startup_metrics.record("tauri_build", build_start.elapsed());
 
     app.run(|app_handle, event| {
         // Suppress unused variable warning (used conditionally per platform)
@@ -485,20 +880,12 @@ pub fn run() {
             #[cfg(target_os = "macos")]
             RunEvent::Opened { urls } => {
                 for url in urls {
-                    if let Ok(path) = url.to_file_path() {
-                        if path.extension().is_some_and(|ext| {
-                            ext.eq_ignore_ascii_case("pptx")
-                                || ext.eq_ignore_ascii_case("opensong")
-                                || ext.eq_ignore_ascii_case("churchprogram")
-                        }) {
-                            println!("[file-association] Opened event: {path:?}");
-
-                            // Emit event to frontend
-                            if let Err(e) =
-                                app_handle.emit("file-opened", path.to_string_lossy().to_string())
-                            {
-                                println!("[file-association] Failed to emit: {e}");
-                            }
+                    if handle_deep_link(app_handle, url.as_str()) {
+                        info!(target: "deep-link", "Opened event: {url}");
+                    } else if let Ok(path) = url.to_file_path() {
+                        if is_importable_file(&path) {
+                            info!(target: "file-association", "Opened event: {path:?}");
+                            forward_file_import(app_handle, path);
                         }
                     }
                 }
@@ -507,7 +894,20 @@ pub fn run() {
                 // Only shutdown sidecar on desktop in release mode (we started it)
                 #[cfg(all(desktop, not(debug_assertions)))]
                 if let Err(e) = server::shutdown_server(app_handle) {
-                    println!("[sidecar] Failed to shut down server on exit: {e}");
+                    log::warn!(target: "sidecar", "Failed to shut down server on exit: {e}");
+                }
+
+                // Leave presenter mode before the window state plugin saves on exit, so the
+                // next launch doesn't restore a fullscreen, undecorated control window
+                #[cfg(desktop)]
+                if matches!(event, RunEvent::ExitRequested { .. }) {
+                    window::restore_control_roles(app_handle);
+                }
+
+                // Runs after the window state plugin has saved on exit
+                #[cfg(desktop)]
+                if matches!(event, RunEvent::Exit) {
+                    reset::on_exit(app_handle);
                 }
             }
             _ => {}
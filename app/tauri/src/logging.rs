@@ -0,0 +1,108 @@
+use log::{Level, LevelFilter, Log, Metadata, Record};
+use std::io::Write;
+
+/// Environment variable holding the log levels, either a single level (`debug`) or a
+/// default plus per-subsystem overrides (`info,webview=debug,sidecar=warn`)
+pub const LOG_LEVEL_ENV: &str = "CHURCH_HUB_LOG";
+
+/// Level used when the environment variable is unset or invalid
+const DEFAULT_LEVEL: LevelFilter = LevelFilter::Info;
+
+/// Prints records as `[subsystem] message`, the format the app has always used,
+/// with the level added for anything other than info. Warnings and errors go to stderr.
+struct Logger {
+    default_level: LevelFilter,
+    /// Per-subsystem (log target) overrides
+    target_levels: Vec<(String, LevelFilter)>,
+}
+
+impl Logger {
+    /// Parses a level spec such as `info,webview=debug`, ignoring invalid entries
+    fn from_spec(spec: &str) -> Self {
+        let mut logger = Logger {
+            default_level: DEFAULT_LEVEL,
+            target_levels: Vec::new(),
+        };
+        for entry in spec.split(',').map(str::trim).filter(|e| !e.is_empty()) {
+            match entry.split_once('=') {
+                Some((target, level)) => match level.trim().parse() {
+                    Ok(level) => logger
+                        .target_levels
+                        .push((target.trim().to_string(), level)),
+                    Err(_) => eprintln!("[logging] Ignoring invalid level in '{entry}'"),
+                },
+                None => match entry.parse() {
+                    Ok(level) => logger.default_level = level,
+                    Err(_) => eprintln!("[logging] Ignoring invalid level '{entry}'"),
+                },
+            }
+        }
+        logger
+    }
+
+    /// Gets the level for a subsystem
+    fn level_for(&self, target: &str) -> LevelFilter {
+        self.target_levels
+            .iter()
+            .find(|(name, _)| name == target)
+            .map(|(_, level)| *level)
+            .unwrap_or(self.default_level)
+    }
+
+    /// Most verbose level enabled for any subsystem
+    fn max_level(&self) -> LevelFilter {
+        self.target_levels
+            .iter()
+            .map(|(_, level)| *level)
+            .fold(self.default_level, std::cmp::max)
+    }
+}
+
+impl Log for Logger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= self.level_for(metadata.target())
+    }
+
+    fn log(&self, record: &Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+        match record.level() {
+            Level::Info => println!("[{}] {}", record.target(), record.args()),
+            Level::Debug | Level::Trace => {
+                println!(
+                    "[{}] {}: {}",
+                    record.target(),
+                    record.level(),
+                    record.args()
+                )
+            }
+            Level::Warn | Level::Error => {
+                eprintln!(
+                    "[{}] {}: {}",
+                    record.target(),
+                    record.level(),
+                    record.args()
+                )
+            }
+        }
+    }
+
+    fn flush(&self) {
+        let _ = std::io::stdout().flush();
+        let _ = std::io::stderr().flush();
+    }
+}
+
+/// Installs the app logger, reading levels from `CHURCH_HUB_LOG`
+/// Log with the subsystem as the target, e.g. `log::info!(target: "webview", "...")`
+pub fn init() {
+    let spec = std::env::var(LOG_LEVEL_ENV).unwrap_or_default();
+    let logger = Logger::from_spec(&spec);
+    let max_level = logger.max_level();
+
+    match log::set_boxed_logger(Box::new(logger)) {
+        Ok(()) => log::set_max_level(max_level),
+        Err(e) => eprintln!("[logging] Failed to install logger: {e}"),
+    }
+}
@@ -0,0 +1,135 @@
+use crate::commands::{ZoomState, LAST_ROUTE_KEY, MEDIA_ROOT_KEY};
+use crate::store;
+use crate::webview::WebviewRegistry;
+use log::{info, warn};
+use serde::Deserialize;
+use std::sync::atomic::{AtomicBool, Ordering};
+use tauri::{AppHandle, Emitter, Manager};
+use tauri_plugin_window_state::AppHandleExt;
+
+/// State for `reset_app_state`
+#[derive(Default)]
+pub struct ResetState {
+    /// Set once the saved window state was reset, so the copy the window state plugin
+    /// writes on exit is deleted too
+    pub window_state_reset: AtomicBool,
+}
+
+/// Which persisted state `reset_app_state` clears
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum ResetScope {
+    /// Everything below, plus any other value in the store
+    All,
+    /// Saved window positions, sizes and fullscreen state
+    WindowState,
+    /// Per-webview zoom levels
+    Zoom,
+    /// Route restored on launch
+    LastRoute,
+    /// Default media folder
+    MediaRoot,
+    /// Display window -> monitor assignments
+    Displays,
+    /// Audio transport key bindings
+    KeyBindings,
+    /// Hardware acceleration and extra WebView2 arguments
+    #[serde(rename = "webview2")]
+    WebView2,
+}
+
+/// Deletes the window state plugin's file
+fn delete_window_state(app: &AppHandle) -> Result<(), String> {
+    let path = app
+        .path()
+        .app_config_dir()
+        .map_err(|e| format!("Failed to resolve app config directory: {e}"))?
+        .join(app.filename());
+    match std::fs::remove_file(&path) {
+        Ok(()) => Ok(()),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+        Err(e) => Err(format!("Failed to delete window state: {e}")),
+    }
+}
+
+/// Resets every webview to 100% zoom
+fn reset_zoom_levels(app: &AppHandle) {
+    if let Some(zoom_state) = app.try_state::<ZoomState>() {
+        zoom_state.zoom_levels.lock().clear();
+    }
+    for webview in app.webviews().values() {
+        if let Err(e) = webview.set_zoom(1.0) {
+            warn!(target: "reset", "Failed to reset zoom of '{}': {e}", webview.label());
+        }
+    }
+}
+
+/// Restores the default transport key bindings and tells the frontend
+fn reset_key_bindings(app: &AppHandle) -> Result<(), String> {
+    store::remove_value(app, crate::keyboard::TRANSPORT_BINDINGS_KEY)?;
+    app.emit(
+        "transport-key-bindings-changed",
+        crate::keyboard::default_transport_bindings(),
+    )
+    .map_err(|e| format!("Failed to emit transport-key-bindings-changed: {e}"))
+}
+
+/// Clears persisted app state back to factory defaults. `confirm` must be true, so a
+/// stray call can't wipe settings. Returns whether a restart is needed for everything
+/// to take effect (window state and WebView2 settings are only read at launch).
+#[tauri::command]
+pub fn reset_app_state(
+    app: AppHandle,
+    state: tauri::State<'_, ResetState>,
+    scope: ResetScope,
+    confirm: bool,
+) -> Result<bool, String> {
+    if !confirm {
+        return Err("Resetting app state requires confirm: true".to_string());
+    }
+    info!(target: "reset", "Resetting app state: {scope:?}");
+
+    match scope {
+        ResetScope::All => {
+            store::clear(&app)?;
+            if let Some(registry) = app.try_state::<WebviewRegistry>() {
+                registry.forget_default_options();
+            }
+            delete_window_state(&app)?;
+            state.window_state_reset.store(true, Ordering::SeqCst);
+            reset_zoom_levels(&app);
+            reset_key_bindings(&app)?;
+        }
+        ResetScope::WindowState => {
+            delete_window_state(&app)?;
+            state.window_state_reset.store(true, Ordering::SeqCst);
+        }
+        ResetScope::Zoom => reset_zoom_levels(&app),
+        ResetScope::LastRoute => store::remove_value(&app, LAST_ROUTE_KEY)?,
+        ResetScope::MediaRoot => store::remove_value(&app, MEDIA_ROOT_KEY)?,
+        ResetScope::Displays => store::remove_value(&app, crate::display::MONITOR_ASSIGNMENTS_KEY)?,
+        ResetScope::KeyBindings => reset_key_bindings(&app)?,
+        ResetScope::WebView2 => {
+            store::remove_value(&app, crate::webview2::HARDWARE_ACCELERATION_KEY)?;
+            store::remove_value(&app, crate::webview2::BROWSER_ARGUMENTS_KEY)?;
+        }
+    }
+
+    Ok(matches!(
+        scope,
+        ResetScope::All | ResetScope::WindowState | ResetScope::WebView2
+    ))
+}
+
+/// Deletes the window state saved on exit if it was reset during this session
+/// (call after the window state plugin has handled `RunEvent::Exit`)
+pub fn on_exit(app: &AppHandle) {
+    let reset = app
+        .try_state::<ResetState>()
+        .is_some_and(|state| state.window_state_reset.load(Ordering::SeqCst));
+    if reset {
+        if let Err(e) = delete_window_state(app) {
+            warn!(target: "reset", "{e}");
+        }
+    }
+}
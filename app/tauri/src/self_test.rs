@@ -0,0 +1,201 @@
+use crate::domain::AppState;
+use log::{info, warn};
+use serde::Serialize;
+use std::time::Duration;
+use tauri::{AppHandle, Manager};
+
+/// Free space below which the disk check warns (a long recording easily takes this much)
+const MIN_FREE_DISK_BYTES: u64 = 2 * 1024 * 1024 * 1024;
+
+/// How long the server has to answer the ping
+const SERVER_PING_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// Outcome of a self-test check
+#[derive(Serialize, Clone, Copy, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub enum CheckStatus {
+    Pass,
+    /// Works, but worth a look before the service
+    Warn,
+    Fail,
+    /// Not checked natively (handled by the frontend)
+    Skipped,
+}
+
+/// Result of one self-test check
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SelfTestCheck {
+    pub name: &'static str,
+    pub status: CheckStatus,
+    pub message: String,
+}
+
+/// Result of `run_self_test`
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SelfTestReport {
+    /// True when no check failed (warnings and skipped checks don't count)
+    pub passed: bool,
+    pub checks: Vec<SelfTestCheck>,
+}
+
+/// Builds a check result
+fn check(name: &'static str, status: CheckStatus, message: impl Into<String>) -> SelfTestCheck {
+    SelfTestCheck {
+        name,
+        status,
+        message: message.into(),
+    }
+}
+
+/// Checks the sidecar answers /ping
+async fn check_server(app: &AppHandle) -> SelfTestCheck {
+    let Some(server_port) = app.try_state::<AppState>().map(|state| state.server_port) else {
+        return check("server", CheckStatus::Fail, "App state not initialized");
+    };
+    let latency = tokio::task::spawn_blocking(move || {
+        crate::server::ping_server_latency(server_port, SERVER_PING_TIMEOUT)
+    })
+    .await
+    .map_err(|e| e.to_string())
+    .and_then(|result| result);
+    match latency {
+        Ok(latency) => check(
+            "server",
+            CheckStatus::Pass,
+            format!("Responded in {:.0} ms", latency.as_secs_f64() * 1000.0),
+        ),
+        Err(e) => check(
+            "server",
+            CheckStatus::Fail,
+            format!("Not responding: {}", e),
+        ),
+    }
+}
+
+/// Checks there is an audio output whose volume can be read, and that it isn't muted
+async fn check_audio_output() -> SelfTestCheck {
+    match crate::volume::get_system_volume().await {
+        Ok(0) => check("audioOutput", CheckStatus::Warn, "System volume is at 0%"),
+        Ok(volume) => check(
+            "audioOutput",
+            CheckStatus::Pass,
+            format!("System volume is at {}%", volume),
+        ),
+        Err(e) => check(
+            "audioOutput",
+            CheckStatus::Fail,
+            format!("No audio output: {}", e),
+        ),
+    }
+}
+
+/// Checks every display with an assigned monitor can find it
+fn check_displays(app: &AppHandle) -> SelfTestCheck {
+    let assignments = crate::display::monitor_assignments(app);
+    if assignments.is_empty() {
+        return check(
+            "displays",
+            CheckStatus::Pass,
+            "No display monitors assigned",
+        );
+    }
+    let monitors = match app.available_monitors() {
+        Ok(monitors) => monitors,
+        Err(e) => {
+            return check(
+                "displays",
+                CheckStatus::Fail,
+                format!("Failed to list monitors: {}", e),
+            )
+        }
+    };
+
+    let mut missing: Vec<String> = assignments
+        .iter()
+        .filter(|(_, name)| !monitors.iter().any(|m| m.name() == Some(*name)))
+        .map(|(label, name)| format!("{} ({})", label, name))
+        .collect();
+    missing.sort();
+    if missing.is_empty() {
+        check(
+            "displays",
+            CheckStatus::Pass,
+            format!("All {} assigned monitors connected", assignments.len()),
+        )
+    } else {
+        check(
+            "displays",
+            CheckStatus::Fail,
+            format!("Monitors not connected: {}", missing.join(", ")),
+        )
+    }
+}
+
+/// Checks the disk holding the media root has room for recordings and imports
+async fn check_disk_space(app: &AppHandle) -> SelfTestCheck {
+    match crate::commands::get_disk_space(app.clone(), None).await {
+        Ok(disk) => {
+            let free_gb = disk.free_bytes as f64 / (1024.0 * 1024.0 * 1024.0);
+            let status = if disk.free_bytes < MIN_FREE_DISK_BYTES {
+                CheckStatus::Warn
+            } else {
+                CheckStatus::Pass
+            };
+            check(
+                "diskSpace",
+                status,
+                format!("{:.1} GB free on {}", free_gb, disk.mount_point),
+            )
+        }
+        Err(e) => check("diskSpace", CheckStatus::Fail, e),
+    }
+}
+
+/// Runs the pre-service readiness checks: server, audio output, assigned display
+/// monitors and disk space. The test tone and the controller connection belong to the
+/// frontend (audio plays in the webview, which also owns the WebSocket), so they are
+/// reported as skipped for the frontend to check alongside.
+#[tauri::command]
+pub async fn run_self_test(app: AppHandle) -> SelfTestReport {
+    let checks = vec![
+        check_server(&app).await,
+        check_audio_output().await,
+        check(
+            "testTone",
+            CheckStatus::Skipped,
+            "Audio plays in the webview; play the tone from the frontend",
+        ),
+        check_displays(&app),
+        check_disk_space(&app).await,
+        check(
+            "controller",
+            CheckStatus::Skipped,
+            "The controller connection is managed by the frontend",
+        ),
+    ];
+
+    for check in &checks {
+        match check.status {
+            CheckStatus::Fail | CheckStatus::Warn => warn!(
+                target: "self-test",
+                "{}: {:?} - {}",
+                check.name,
+                check.status,
+                check.message
+            ),
+            _ => info!(
+                target: "self-test",
+                "{}: {:?} - {}",
+                check.name,
+                check.status,
+                check.message
+            ),
+        }
+    }
+    SelfTestReport {
+        passed: checks.iter().all(|check| check.status != CheckStatus::Fail),
+        checks,
+    }
+}
@@ -1,8 +1,14 @@
-use crate::domain::AppState;
+use crate::domain::{AppState, ServerResourceUsage};
+use log::{error, info, warn};
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::path::PathBuf;
 use std::process::Command;
+use std::sync::atomic::Ordering;
 use std::time::{Duration, Instant};
+use sysinfo::{Pid, ProcessRefreshKind, ProcessesToUpdate, System, UpdateKind};
 use tauri::path::BaseDirectory;
-use tauri::{AppHandle, Manager};
+use tauri::{AppHandle, Emitter, Manager};
 use tauri_plugin_shell::{process::CommandEvent, ShellExt};
 use tokio::time::sleep;
 
@@ -13,6 +19,67 @@ pub struct PortProcessInfo {
     pub name: String,
 }
 
+/// Store key for sidecar environment overrides (e.g. `NODE_ENV=staging` for QA builds)
+pub const SIDECAR_ENVIRONMENT_KEY: &str = "sidecarEnvironment";
+
+/// Sidecar environment overrides from the environment, as space separated `NAME=value`
+/// pairs; these take precedence over the stored overrides
+const SIDECAR_ENVIRONMENT_ENV: &str = "CHURCH_HUB_SIDECAR_ENV";
+
+/// Environment the sidecar is started with unless overridden
+const DEFAULT_SIDECAR_ENVIRONMENT: &[(&str, &str)] = &[
+    ("TZ", "UTC"),
+    ("NODE_ENV", "production"),
+    ("TAURI_MODE", "true"),
+];
+
+/// Variables set by the app itself that overrides may not replace
+const RESERVED_SIDECAR_VARIABLES: &[&str] = &["PORT", "CLIENT_DIST_PATH"];
+
+/// Checks a sidecar environment variable name can be overridden
+pub fn validate_sidecar_variable(name: &str) -> Result<(), String> {
+    if name.is_empty() || name.contains(|c: char| c == '=' || c.is_whitespace()) {
+        return Err(format!("Invalid environment variable name '{name}'"));
+    }
+    if RESERVED_SIDECAR_VARIABLES.contains(&name) {
+        return Err(format!("'{name}' is managed by the app"));
+    }
+    Ok(())
+}
+
+/// Builds the sidecar environment: the defaults, then the stored overrides, then
+/// `CHURCH_HUB_SIDECAR_ENV`. Invalid or reserved names are skipped.
+pub fn sidecar_environment(app_handle: &AppHandle) -> BTreeMap<String, String> {
+    let mut environment: BTreeMap<String, String> = DEFAULT_SIDECAR_ENVIRONMENT
+        .iter()
+        .map(|(name, value)| (name.to_string(), value.to_string()))
+        .collect();
+
+    let stored: BTreeMap<String, String> =
+        crate::store::get_value(app_handle, SIDECAR_ENVIRONMENT_KEY).unwrap_or_default();
+    let from_env = std::env::var(SIDECAR_ENVIRONMENT_ENV).unwrap_or_default();
+    let from_env = from_env.split_whitespace().filter_map(|pair| {
+        let parsed = pair.split_once('=');
+        if parsed.is_none() {
+            warn!(target: "sidecar", "Ignoring {SIDECAR_ENVIRONMENT_ENV} entry {pair:?}");
+        }
+        parsed.map(|(name, value)| (name.to_string(), value.to_string()))
+    });
+
+    for (name, value) in stored.into_iter().chain(from_env) {
+        match validate_sidecar_variable(&name) {
+            Ok(()) => {
+                environment.insert(name, value);
+            }
+            Err(e) => warn!(target: "sidecar", "Ignoring override: {e}"),
+        }
+    }
+    environment
+}
+
+/// Interval between the two samples used to measure process CPU usage
+const CPU_SAMPLE_INTERVAL: Duration = Duration::from_millis(250);
+
 /// Checks if a port is in use by attempting to bind to it
 /// Returns true if the port is already in use (cannot bind)
 pub fn is_port_in_use(port: u16) -> bool {
@@ -146,7 +213,7 @@ pub fn kill_port_process(port: u16) -> Result<(), String> {
 
     let pid_str = String::from_utf8_lossy(&output.stdout);
     for pid in pid_str.trim().lines() {
-        println!("[port-conflict] Killing process with PID: {}", pid);
+        info!(target: "port-conflict", "Killing process with PID: {}", pid);
         let kill_result = Command::new("kill")
             .args(["-9", pid])
             .output()
@@ -162,7 +229,7 @@ pub fn kill_port_process(port: u16) -> Result<(), String> {
 #[cfg(target_os = "windows")]
 pub fn kill_port_process(port: u16) -> Result<(), String> {
     if let Some(info) = get_port_process_info(port) {
-        println!("[port-conflict] Killing process with PID: {}", info.pid);
+        info!(target: "port-conflict", "Killing process with PID: {}", info.pid);
         let kill_result = Command::new("taskkill")
             .args(["/F", "/PID", &info.pid.to_string()])
             .output()
@@ -190,7 +257,7 @@ pub fn kill_port_process(port: u16) -> Result<(), String> {
 
     let pid_str = String::from_utf8_lossy(&output.stdout);
     for pid in pid_str.trim().lines() {
-        println!("[port-conflict] Killing process with PID: {}", pid);
+        info!(target: "port-conflict", "Killing process with PID: {}", pid);
         let kill_result = Command::new("kill")
             .args(["-9", pid])
             .output()
@@ -203,13 +270,76 @@ pub fn kill_port_process(port: u16) -> Result<(), String> {
     Ok(())
 }
 
-/// Waits for the server to be ready by polling the /ping endpoint (async version)
-pub async fn wait_for_server_ready_async(port: u16, timeout_secs: u64) -> Result<(), String> {
+/// Times one request to the /ping endpoint (blocking)
+pub fn ping_server_latency(port: u16, timeout: Duration) -> Result<Duration, String> {
+    let url = format!("http://127.0.0.1:{}/ping", port);
+    let start = Instant::now();
+    let response = ureq::get(&url)
+        .timeout(timeout)
+        .call()
+        .map_err(|e| format!("Server did not respond to ping: {}", e))?;
+    let elapsed = start.elapsed();
+
+    if response.status() != 200 {
+        return Err(format!("Server ping returned status {}", response.status()));
+    }
+    Ok(elapsed)
+}
+
+/// Asks the server for its version via the /version endpoint (blocking).
+/// Accepts a JSON `{ "version": ... }` body or the bare version as text.
+pub fn fetch_server_version(port: u16, timeout: Duration) -> Result<String, String> {
+    let url = format!("http://127.0.0.1:{}/version", port);
+    let body = ureq::get(&url)
+        .timeout(timeout)
+        .call()
+        .map_err(|e| format!("Server did not report its version: {}", e))?
+        .into_string()
+        .map_err(|e| format!("Failed to read server version: {}", e))?;
+
+    let version = match serde_json::from_str::<serde_json::Value>(&body) {
+        Ok(serde_json::Value::Object(map)) => map
+            .get("version")
+            .and_then(|version| version.as_str())
+            .map(str::to_string),
+        _ => Some(body.trim().to_string()),
+    };
+    version
+        .filter(|version| !version.is_empty())
+        .ok_or_else(|| "Server version response has no version".to_string())
+}
+
+/// Checks once whether the server answers the /ping endpoint (blocking)
+pub fn is_server_responding(port: u16, timeout: Duration) -> bool {
+    ping_server_latency(port, timeout).is_ok()
+}
+
+/// Startup time after which `server-progress` reports the server as slow to start
+pub const SLOW_START_AFTER: Duration = Duration::from_secs(5);
+
+/// Payload of the `server-progress` event, emitted every second while the server starts
+#[derive(Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ServerStartupProgress {
+    pub elapsed_secs: u64,
+    pub timeout_secs: u64,
+    /// Set once startup takes longer than `SLOW_START_AFTER`
+    pub slow: bool,
+}
+
+/// Polls the /ping endpoint until the server answers, calling `on_progress` with the
+/// elapsed time once per second
+async fn poll_until_ready(
+    port: u16,
+    timeout_secs: u64,
+    mut on_progress: impl FnMut(Duration),
+) -> Result<(), String> {
     let start = Instant::now();
     let timeout = Duration::from_secs(timeout_secs);
     let url = format!("http://127.0.0.1:{}/ping", port);
+    let mut reported_secs = 0;
 
-    println!("[sidecar] Waiting for server to be ready on port {port}...");
+    info!(target: "sidecar", "Waiting for server to be ready on port {port}...");
 
     while start.elapsed() < timeout {
         // Use tokio::task::spawn_blocking for the HTTP request to avoid blocking async runtime
@@ -223,8 +353,9 @@ pub async fn wait_for_server_ready_async(port: u16, timeout_secs: u64) -> Result
 
         match result {
             Ok(Ok(response)) if response.status() == 200 => {
-                println!(
-                    "[sidecar] Server is ready! (took {:.2}s)",
+                info!(
+                    target: "sidecar",
+                    "Server is ready! (took {:.2}s)",
                     start.elapsed().as_secs_f64()
                 );
                 return Ok(());
@@ -233,6 +364,11 @@ pub async fn wait_for_server_ready_async(port: u16, timeout_secs: u64) -> Result
                 sleep(Duration::from_millis(100)).await;
             }
         }
+
+        if start.elapsed().as_secs() > reported_secs {
+            reported_secs = start.elapsed().as_secs();
+            on_progress(start.elapsed());
+        }
     }
 
     Err(format!(
@@ -241,49 +377,245 @@ pub async fn wait_for_server_ready_async(port: u16, timeout_secs: u64) -> Result
     ))
 }
 
+/// Waits for the server to be ready by polling the /ping endpoint (async version)
+pub async fn wait_for_server_ready_async(port: u16, timeout_secs: u64) -> Result<(), String> {
+    poll_until_ready(port, timeout_secs, |_| {}).await
+}
+
+/// Emits a server startup event
+fn emit_startup_event<S: Serialize + Clone>(app_handle: &AppHandle, event: &str, payload: S) {
+    if let Err(e) = app_handle.emit(event, payload) {
+        warn!(target: "sidecar", "Failed to emit {event}: {e}");
+    }
+}
+
+/// Waits for the server like `wait_for_server_ready_async`, reporting progress so a slow
+/// start doesn't look like a hang: emits `server-starting`, then `server-progress` every
+/// second, then `server-ready` or `server-failed` (with the error message)
+pub async fn wait_for_server_startup(
+    app_handle: &AppHandle,
+    port: u16,
+    timeout_secs: u64,
+) -> Result<(), String> {
+    emit_startup_event(app_handle, "server-starting", ());
+    let result = poll_until_ready(port, timeout_secs, |elapsed| {
+        let progress = ServerStartupProgress {
+            elapsed_secs: elapsed.as_secs(),
+            timeout_secs,
+            slow: elapsed >= SLOW_START_AFTER,
+        };
+        emit_startup_event(app_handle, "server-progress", progress);
+    })
+    .await;
+
+    match &result {
+        Ok(()) => emit_startup_event(app_handle, "server-ready", ()),
+        Err(e) => emit_startup_event(app_handle, "server-failed", e.clone()),
+    }
+    result
+}
+
 /// Waits for the server to be ready by polling the /ping endpoint (sync version for setup hook)
 pub fn wait_for_server_ready(port: u16, timeout_secs: u64) -> Result<(), String> {
     // Run the async version using Tauri's runtime
     tauri::async_runtime::block_on(wait_for_server_ready_async(port, timeout_secs))
 }
 
+/// Name of the bundled sidecar binary
+const SIDECAR_NAME: &str = "church-hub-sidecar";
+
+/// Gets the PID of the running sidecar, if any
+pub fn sidecar_pid(app_handle: &AppHandle) -> Option<u32> {
+    let state = app_handle.try_state::<AppState>()?;
+    let pid = state.server.lock().as_ref().map(|child| child.pid());
+    pid
+}
+
+/// Samples CPU and memory usage of a process, returning None if it isn't running
+/// Blocks for the CPU sampling interval, since CPU usage is measured between two refreshes
+pub fn process_resource_usage(pid: u32) -> Option<ServerResourceUsage> {
+    let pid = Pid::from_u32(pid);
+    let refresh_kind = ProcessRefreshKind::nothing().with_cpu().with_memory();
+    let mut system = System::new();
+
+    system.refresh_processes_specifics(ProcessesToUpdate::Some(&[pid]), true, refresh_kind);
+    std::thread::sleep(CPU_SAMPLE_INTERVAL.max(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL));
+    system.refresh_processes_specifics(ProcessesToUpdate::Some(&[pid]), true, refresh_kind);
+
+    system.process(pid).map(|process| ServerResourceUsage {
+        pid: pid.as_u32(),
+        cpu_percent: process.cpu_usage(),
+        memory_bytes: process.memory(),
+    })
+}
+
+/// A sidecar process that isn't the one the app is managing, e.g. left running by a crash
+#[derive(Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OrphanedSidecar {
+    pub pid: u32,
+    /// Seconds since the Unix epoch
+    pub started_at: u64,
+}
+
+/// Finds sidecar processes other than the running one. Only processes whose executable is
+/// our sidecar binary (by full path) match, so unrelated processes are never picked up.
+pub fn find_orphaned_sidecars(app_handle: &AppHandle) -> Result<Vec<OrphanedSidecar>, String> {
+    let sidecar = sidecar_path()?;
+    let current = sidecar_pid(app_handle);
+
+    let mut system = System::new();
+    system.refresh_processes_specifics(
+        ProcessesToUpdate::All,
+        true,
+        ProcessRefreshKind::nothing().with_exe(UpdateKind::OnlyIfNotSet),
+    );
+
+    let mut orphans: Vec<OrphanedSidecar> = system
+        .processes()
+        .values()
+        .filter(|process| process.exe() == Some(sidecar.as_path()))
+        .filter(|process| Some(process.pid().as_u32()) != current)
+        .map(|process| OrphanedSidecar {
+            pid: process.pid().as_u32(),
+            started_at: process.start_time(),
+        })
+        .collect();
+    orphans.sort_by_key(|orphan| orphan.pid);
+    Ok(orphans)
+}
+
+/// Kills orphaned sidecar processes, returning the PIDs killed
+pub fn kill_orphaned_sidecars(app_handle: &AppHandle) -> Result<Vec<u32>, String> {
+    let orphans = find_orphaned_sidecars(app_handle)?;
+    if orphans.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let mut system = System::new();
+    let pids: Vec<Pid> = orphans.iter().map(|o| Pid::from_u32(o.pid)).collect();
+    system.refresh_processes_specifics(
+        ProcessesToUpdate::Some(&pids),
+        true,
+        ProcessRefreshKind::nothing(),
+    );
+
+    let mut killed = Vec::new();
+    for pid in pids {
+        match system.process(pid) {
+            Some(process) if process.kill() => {
+                info!(target: "sidecar", "Killed orphaned sidecar (PID {pid})");
+                killed.push(pid.as_u32());
+            }
+            Some(_) => warn!(target: "sidecar", "Failed to kill orphaned sidecar (PID {pid})"),
+            None => {}
+        }
+    }
+    Ok(killed)
+}
+
+/// Resolves the sidecar binary path the same way the shell plugin does (next to the app executable)
+fn sidecar_path() -> Result<PathBuf, String> {
+    let exe = tauri::utils::platform::current_exe()
+        .map_err(|e| format!("Failed to resolve app executable: {e}"))?;
+    let exe_dir = exe
+        .parent()
+        .ok_or_else(|| "App executable has no parent directory".to_string())?;
+
+    let path = exe_dir.join(SIDECAR_NAME);
+    #[cfg(windows)]
+    let path = path.with_extension("exe");
+    Ok(path)
+}
+
+/// Checks that the sidecar binary exists and is executable
+pub fn validate_sidecar() -> Result<(), String> {
+    let path = sidecar_path()?;
+    let reinstall_hint = "Please reinstall Church Hub to restore it.";
+
+    let metadata = std::fs::metadata(&path).map_err(|_| {
+        format!(
+            "The Church Hub server is missing ({}). {}",
+            path.display(),
+            reinstall_hint
+        )
+    })?;
+    if !metadata.is_file() {
+        return Err(format!(
+            "The Church Hub server is not a file ({}). {}",
+            path.display(),
+            reinstall_hint
+        ));
+    }
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        if metadata.permissions().mode() & 0o111 == 0 {
+            return Err(format!(
+                "The Church Hub server is not executable ({}). {}",
+                path.display(),
+                reinstall_hint
+            ));
+        }
+    }
+
+    Ok(())
+}
+
 pub fn start_server(app_handle: &AppHandle, server_port: u16) -> Result<(), String> {
-    println!("[sidecar] Starting server...");
+    info!(target: "sidecar", "Starting server...");
     if let Some(app_state) = app_handle.try_state::<AppState>() {
         if app_state.server.lock().is_some() {
-            println!("[sidecar] Server is already running.");
+            info!(target: "sidecar", "Server is already running.");
             return Ok(());
         }
     }
 
+    // Fail fast with an actionable message instead of timing out on a missing binary
+    if let Err(message) = validate_sidecar() {
+        error!(target: "sidecar", "{message}");
+        if let Some(app_state) = app_handle.try_state::<AppState>() {
+            *app_state.server_error.lock() = Some(message.clone());
+        }
+        if let Err(e) = app_handle.emit("server-missing", message.clone()) {
+            warn!(target: "sidecar", "Failed to emit server-missing: {e}");
+        }
+        return Err(message);
+    }
+
     let t = Instant::now();
     let shell = app_handle.shell();
     let mut sidecar = shell
-        .sidecar("church-hub-sidecar")
+        .sidecar(SIDECAR_NAME)
         .map_err(|err| err.to_string())?;
-    println!("[startup] sidecar_create: {:?}", t.elapsed());
+    info!(target: "startup", "sidecar_create: {:?}", t.elapsed());
 
     let t = Instant::now();
-    sidecar = sidecar.env("TZ", "UTC");
-    sidecar = sidecar.env("NODE_ENV", "production");
-    sidecar = sidecar.env("TAURI_MODE", "true");
+    let environment = sidecar_environment(app_handle);
+    // Only the names: overrides can carry tokens and the log ends up in support bundles
+    let names: Vec<&String> = environment.keys().collect();
+    info!(target: "sidecar", "Environment variables: {names:?}");
+    sidecar = sidecar.envs(environment);
     sidecar = sidecar.env("PORT", server_port.to_string());
 
     // Pass the client dist path for static file serving
     if let Ok(resource_dir) = app_handle.path().resolve("client-dist", BaseDirectory::Resource) {
         let resource_path = resource_dir.to_string_lossy().to_string();
-        println!("[sidecar] Client dist path: {}", resource_path);
+        info!(target: "sidecar", "Client dist path: {}", resource_path);
         sidecar = sidecar.env("CLIENT_DIST_PATH", resource_path);
     }
-    println!("[startup] sidecar_env_setup: {:?}", t.elapsed());
+    info!(target: "startup", "sidecar_env_setup: {:?}", t.elapsed());
 
     let t = Instant::now();
     let (mut rx, child) = sidecar.spawn().map_err(|err| err.to_string())?;
-    println!("[startup] sidecar_process_spawn: {:?}", t.elapsed());
+    info!(target: "startup", "sidecar_process_spawn: {:?}", t.elapsed());
 
     if let Some(app_state) = app_handle.try_state::<AppState>() {
         let mut server_lock = app_state.server.lock();
         *server_lock = Some(child);
+        app_state.server_managed.store(true, Ordering::SeqCst);
+        *app_state.server_error.lock() = None;
     }
 
     let app_handle_clone = app_handle.clone();
@@ -294,21 +626,29 @@ pub fn start_server(app_handle: &AppHandle, server_port: u16) -> Result<(), Stri
                 CommandEvent::Stdout(data) => {
                     if let Ok(text) = String::from_utf8(data) {
                         let line = text.trim();
-                        println!("[sidecar] stdout: {line}");
+                        info!(target: "sidecar", "stdout: {line}");
+                        if let Some(app_state) = app_handle_clone.try_state::<AppState>() {
+                            app_state.push_server_log(format!("stdout: {line}"));
+                        }
                     }
                 }
                 CommandEvent::Stderr(data) => {
                     if let Ok(text) = String::from_utf8(data) {
-                        eprintln!("[sidecar] stderr: {}", text.trim());
+                        let line = text.trim();
+                        warn!(target: "sidecar", "stderr: {line}");
+                        if let Some(app_state) = app_handle_clone.try_state::<AppState>() {
+                            app_state.push_server_log(format!("stderr: {line}"));
+                        }
                     }
                 }
                 CommandEvent::Terminated(code) => {
-                    println!("[sidecar] Server terminated with code {code:?}");
+                    info!(target: "sidecar", "Server terminated with code {code:?}");
 
                     // Clear server reference
                     if let Some(app_state) = app_handle_clone.try_state::<AppState>() {
                         let mut server_lock = app_state.server.lock();
                         *server_lock = None;
+                        app_state.server_managed.store(false, Ordering::SeqCst);
                     }
                 }
                 _ => {}
@@ -319,22 +659,27 @@ pub fn start_server(app_handle: &AppHandle, server_port: u16) -> Result<(), Stri
 }
 
 pub fn shutdown_server(app_handle: &AppHandle) -> Result<(), String> {
-    println!("[sidecar] Shutting down server...");
+    info!(target: "sidecar", "Shutting down server...");
     if let Some(app_state) = app_handle.try_state::<AppState>() {
+        if !app_state.server_managed.load(Ordering::SeqCst) {
+            info!(target: "sidecar", "Server was not started by the app. Shutdown skipped.");
+            return Ok(());
+        }
         let mut server_lock = app_state.server.lock();
         if server_lock.is_none() {
-            println!("[sidecar] Server is not running. Shutdown not needed.");
+            info!(target: "sidecar", "Server is not running. Shutdown not needed.");
             return Ok(());
         }
+        app_state.server_managed.store(false, Ordering::SeqCst);
         if let Some(mut server) = server_lock.take() {
             server.write("SIDECAR SHUTDOWN\n".as_bytes()).ok();
             match server.kill() {
                 Ok(_) => {
-                    println!("[sidecar] Server terminated successfully.");
+                    info!(target: "sidecar", "Server terminated successfully.");
                     return Ok(());
                 }
                 Err(err) => {
-                    println!("[sidecar] Failed to terminate server.");
+                    warn!(target: "sidecar", "Failed to terminate server.");
                     return Err(err.to_string());
                 }
             }
@@ -343,29 +688,82 @@ pub fn shutdown_server(app_handle: &AppHandle) -> Result<(), String> {
     Ok(())
 }
 
+/// Progress of a server restart, emitted to the frontend as `server-restart-progress`
+#[derive(Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ServerRestartProgress {
+    /// One of: stopping, starting, waiting, ready, failed
+    pub stage: &'static str,
+    pub message: Option<String>,
+}
+
+fn emit_restart_progress(app_handle: &AppHandle, stage: &'static str, message: Option<String>) {
+    if let Err(e) = app_handle.emit(
+        "server-restart-progress",
+        ServerRestartProgress { stage, message },
+    ) {
+        warn!(target: "sidecar", "Failed to emit restart progress: {e}");
+    }
+}
+
 /// Restarts the sidecar server (async version - preferred)
+/// Only one restart can run at a time; concurrent calls fail immediately.
 pub async fn restart_server_async(app_handle: &AppHandle) -> Result<(), String> {
-    println!("[sidecar] Restarting server...");
+    let app_state = app_handle
+        .try_state::<AppState>()
+        .ok_or_else(|| "App state not initialized".to_string())?;
 
-    // Get the server port from app state
-    let server_port = if let Some(app_state) = app_handle.try_state::<AppState>() {
-        app_state.server_port
-    } else {
-        3000 // fallback
-    };
+    if app_state.server_restarting.swap(true, Ordering::SeqCst) {
+        info!(target: "sidecar", "Restart already in progress, ignoring request.");
+        return Err("Server restart already in progress".to_string());
+    }
+
+    info!(target: "sidecar", "Restarting server...");
+    let result = restart_server_steps(app_handle, app_state.server_port).await;
+    app_state.server_restarting.store(false, Ordering::SeqCst);
+
+    match &result {
+        Ok(()) => {
+            info!(target: "sidecar", "Server restarted successfully.");
+            emit_restart_progress(app_handle, "ready", None);
+        }
+        Err(err) => {
+            error!(target: "sidecar", "Server restart failed: {err}");
+            emit_restart_progress(app_handle, "failed", Some(err.clone()));
+        }
+    }
+    result
+}
+
+async fn restart_server_steps(app_handle: &AppHandle, server_port: u16) -> Result<(), String> {
+    // A server we didn't spawn can't be stopped, and starting a sidecar next to it
+    // would only hit the port conflict
+    let managed = app_handle
+        .try_state::<AppState>()
+        .is_some_and(|state| state.server_managed.load(Ordering::SeqCst));
+    if !managed {
+        let responding = tokio::task::spawn_blocking(move || {
+            is_server_responding(server_port, Duration::from_millis(500))
+        })
+        .await
+        .unwrap_or(false);
+        if responding {
+            return Err("Server was not started by the app and cannot be restarted".to_string());
+        }
+    }
 
     // Shutdown the server
+    emit_restart_progress(app_handle, "stopping", None);
     shutdown_server(app_handle)?;
 
     // Wait a bit for cleanup using async sleep (doesn't block main thread)
     sleep(Duration::from_millis(500)).await;
 
     // Start the server again
+    emit_restart_progress(app_handle, "starting", None);
     start_server(app_handle, server_port)?;
 
     // Wait for server to be ready using async version
-    wait_for_server_ready_async(server_port, 30).await?;
-
-    println!("[sidecar] Server restarted successfully.");
-    Ok(())
+    emit_restart_progress(app_handle, "waiting", None);
+    wait_for_server_ready_async(server_port, 30).await
 }
@@ -0,0 +1,166 @@
+use crate::commands::{reset_zoom, toggle_devtools, zoom_in, zoom_out, ZoomState};
+use crate::keyboard::TransportAction;
+use crate::store;
+use crate::window::MAIN_WINDOW_LABEL;
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::str::FromStr;
+use tauri::{AppHandle, Emitter, Manager};
+use tauri_plugin_global_shortcut::{GlobalShortcutExt, Shortcut, ShortcutState};
+
+/// Store key for the global shortcut bindings (action -> accelerator)
+pub const GLOBAL_SHORTCUTS_KEY: &str = "globalShortcuts";
+
+/// Seconds seeked by the seek forward/backward shortcuts
+const SEEK_SECONDS: f64 = 10.0;
+
+/// App action a global shortcut can trigger
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "camelCase")]
+pub enum ShortcutAction {
+    PlayPause,
+    SeekForward,
+    SeekBackward,
+    ZoomIn,
+    ZoomOut,
+    ResetZoom,
+    ToggleDevtools,
+}
+
+/// Reads the saved bindings (none by default, so no system-wide keys are taken
+/// until the user binds them)
+fn saved_shortcuts(app: &AppHandle) -> BTreeMap<ShortcutAction, String> {
+    store::get_value(app, GLOBAL_SHORTCUTS_KEY).unwrap_or_default()
+}
+
+/// Parses an accelerator such as `CmdOrCtrl+Shift+P`
+fn parse_accelerator(accelerator: &str) -> Result<Shortcut, String> {
+    Shortcut::from_str(accelerator)
+        .map_err(|e| format!("Invalid shortcut '{}': {}", accelerator, e))
+}
+
+/// Runs the command bound to an action against the main window
+fn run_action(app: &AppHandle, action: ShortcutAction) {
+    let transport = match action {
+        ShortcutAction::PlayPause => Some(TransportAction::PlayPause),
+        ShortcutAction::SeekForward => Some(TransportAction::SeekRelative {
+            seconds: SEEK_SECONDS,
+        }),
+        ShortcutAction::SeekBackward => Some(TransportAction::SeekRelative {
+            seconds: -SEEK_SECONDS,
+        }),
+        _ => None,
+    };
+    if let Some(transport) = transport {
+        if let Err(e) = app.emit("audio-transport", transport) {
+            warn!(target: "shortcuts", "Failed to emit audio-transport: {}", e);
+        }
+        return;
+    }
+
+    let Some(webview) = app.get_webview(MAIN_WINDOW_LABEL) else {
+        return;
+    };
+    let zoom_state = app.state::<ZoomState>();
+    let result = match action {
+        ShortcutAction::ZoomIn => zoom_in(webview, zoom_state).map(|_| ()),
+        ShortcutAction::ZoomOut => zoom_out(webview, zoom_state).map(|_| ()),
+        ShortcutAction::ResetZoom => reset_zoom(webview, zoom_state).map(|_| ()),
+        ShortcutAction::ToggleDevtools => {
+            toggle_devtools(webview);
+            Ok(())
+        }
+        _ => Ok(()),
+    };
+    if let Err(e) = result {
+        warn!(target: "shortcuts", "Shortcut action {:?} failed: {}", action, e);
+    }
+}
+
+/// Registers a global shortcut that triggers an action
+fn register(app: &AppHandle, action: ShortcutAction, shortcut: Shortcut) -> Result<(), String> {
+    app.global_shortcut()
+        .on_shortcut(shortcut, move |app, _shortcut, event| {
+            if event.state == ShortcutState::Pressed {
+                run_action(app, action);
+            }
+        })
+        .map_err(|e| format!("Failed to register shortcut: {}", e))
+}
+
+/// Registers the saved global shortcuts, skipping (and logging) any that fail
+pub fn register_saved_shortcuts(app: &AppHandle) {
+    for (action, accelerator) in saved_shortcuts(app) {
+        let result =
+            parse_accelerator(&accelerator).and_then(|shortcut| register(app, action, shortcut));
+        match result {
+            Ok(()) => info!(target: "shortcuts", "Registered {} for {:?}", accelerator, action),
+            Err(e) => warn!(target: "shortcuts", "Skipping {:?}: {}", action, e),
+        }
+    }
+}
+
+/// Gets the global shortcut bindings (action -> accelerator)
+#[tauri::command]
+pub fn get_shortcuts(app: AppHandle) -> BTreeMap<ShortcutAction, String> {
+    saved_shortcuts(&app)
+}
+
+/// Binds an action to a global shortcut, or unbinds it when `accelerator` is None.
+/// Fails if the accelerator is invalid, bound to another action or already registered
+/// elsewhere (e.g. by the frontend). The binding is persisted and registered right away.
+#[tauri::command]
+pub fn set_shortcut(
+    app: AppHandle,
+    action: ShortcutAction,
+    accelerator: Option<String>,
+) -> Result<(), String> {
+    let mut shortcuts = saved_shortcuts(&app);
+    let previous = shortcuts
+        .get(&action)
+        .and_then(|accelerator| parse_accelerator(accelerator).ok());
+
+    let shortcut = match &accelerator {
+        Some(accelerator) => {
+            let shortcut = parse_accelerator(accelerator)?;
+            let conflict = shortcuts.iter().find(|(other, bound)| {
+                **other != action && parse_accelerator(bound).ok() == Some(shortcut)
+            });
+            if let Some((other, _)) = conflict {
+                return Err(format!(
+                    "Shortcut '{}' is already bound to {:?}",
+                    accelerator, other
+                ));
+            }
+            if previous != Some(shortcut) && app.global_shortcut().is_registered(shortcut) {
+                return Err(format!("Shortcut '{}' is already in use", accelerator));
+            }
+            Some(shortcut)
+        }
+        None => None,
+    };
+    info!(target: "shortcuts", "Setting {:?} to {:?}", action, accelerator);
+
+    if let Some(previous) = previous {
+        if let Err(e) = app.global_shortcut().unregister(previous) {
+            warn!(target: "shortcuts", "Failed to unregister previous shortcut: {}", e);
+        }
+    }
+    match (shortcut, accelerator) {
+        (Some(shortcut), Some(accelerator)) => {
+            if let Err(e) = register(&app, action, shortcut) {
+                // Keep the old binding working if the new one can't be registered
+                if let Some(previous) = previous {
+                    let _ = register(&app, action, previous);
+                }
+                return Err(e);
+            }
+            shortcuts.insert(action, accelerator);
+        }
+        _ => {
+            shortcuts.remove(&action);
+        }
+    }
+    store::set_value(&app, GLOBAL_SHORTCUTS_KEY, &shortcuts)
+}
@@ -0,0 +1,152 @@
+use crate::commands::ZoomState;
+use crate::display::WindowGeometry;
+use crate::server::SIDECAR_ENVIRONMENT_KEY;
+use crate::splash::SPLASH_WINDOW_LABEL;
+use crate::store;
+use crate::webview::WebviewRegistry;
+use crate::webview2::{BROWSER_ARGUMENTS_KEY, HARDWARE_ACCELERATION_KEY};
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::collections::BTreeMap;
+use tauri::{AppHandle, Emitter, Manager};
+
+/// Format version of exported show states, bumped when older apps can't import a change
+const SHOW_STATE_VERSION: u64 = 1;
+
+/// Settings that describe the machine rather than the show; they are left out of
+/// exports and kept as they are on import
+const MACHINE_KEYS: &[&str] = &[
+    HARDWARE_ACCELERATION_KEY,
+    BROWSER_ARGUMENTS_KEY,
+    SIDECAR_ENVIRONMENT_KEY,
+];
+
+/// Snapshot of everything needed to continue a service on another machine or after a crash
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ShowState {
+    pub version: u64,
+    /// Version of the app that exported the state
+    pub app_version: String,
+    /// Persisted settings: media root, display presets and monitor assignments, volume
+    /// limit, key bindings, cue recordings and so on
+    pub settings: Map<String, Value>,
+    /// Geometry of the open windows, by label
+    pub windows: BTreeMap<String, WindowGeometry>,
+    /// Zoom levels, by webview label
+    pub zoom_levels: BTreeMap<String, f64>,
+}
+
+/// Checks if a setting belongs to the machine rather than the show
+fn is_machine_key(key: &str) -> bool {
+    MACHINE_KEYS.contains(&key)
+}
+
+/// Exports the persisted settings, window layout and zoom levels as one snapshot
+#[tauri::command]
+pub fn export_show_state(app: AppHandle) -> ShowState {
+    let mut settings = store::read_all(&app);
+    settings.retain(|key, _| !is_machine_key(key));
+
+    let windows = app
+        .webview_windows()
+        .into_iter()
+        .filter(|(label, _)| label != SPLASH_WINDOW_LABEL)
+        .filter_map(|(label, window)| {
+            WindowGeometry::capture(&window)
+                .map_err(|e| warn!(target: "show-state", "Skipping window '{label}': {e}"))
+                .ok()
+                .map(|geometry| (label, geometry))
+        })
+        .collect();
+
+    let zoom_levels = app
+        .try_state::<ZoomState>()
+        .map(|zoom_state| {
+            zoom_state
+                .zoom_levels
+                .lock()
+                .iter()
+                .map(|(label, level)| (label.clone(), *level))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    info!(target: "show-state", "Exported show state");
+    ShowState {
+        version: SHOW_STATE_VERSION,
+        app_version: app.package_info().version.to_string(),
+        settings,
+        windows,
+        zoom_levels,
+    }
+}
+
+/// Imports a snapshot from `export_show_state`. The persisted settings are replaced
+/// (machine-specific ones such as hardware acceleration are kept), and the window
+/// geometry and zoom levels are applied to the windows and webviews that are open.
+/// Nothing changes if the snapshot is invalid or from a newer, incompatible version.
+/// Emits `show-state-imported` so the frontend can reload its settings; settings only
+/// read at launch (e.g. global shortcuts) take effect after a restart.
+#[tauri::command]
+pub fn import_show_state(app: AppHandle, state: Value) -> Result<(), String> {
+    let version = state
+        .get("version")
+        .and_then(Value::as_u64)
+        .ok_or_else(|| "Not a show state export".to_string())?;
+    if version > SHOW_STATE_VERSION {
+        return Err(format!(
+            "Show state version {} is newer than this app supports ({}), update the app first",
+            version, SHOW_STATE_VERSION
+        ));
+    }
+    let state: ShowState =
+        serde_json::from_value(state).map_err(|e| format!("Invalid show state: {}", e))?;
+    info!(
+        target: "show-state",
+        "Importing show state exported by version {}",
+        state.app_version
+    );
+
+    store::update_all(&app, |settings| {
+        settings.retain(|key, _| is_machine_key(key));
+        settings.extend(
+            state
+                .settings
+                .into_iter()
+                .filter(|(key, _)| !is_machine_key(key)),
+        );
+    })?;
+    if let Some(registry) = app.try_state::<WebviewRegistry>() {
+        registry.forget_default_options();
+    }
+
+    for (label, geometry) in &state.windows {
+        if let Some(window) = app.get_webview_window(label) {
+            if let Err(e) = geometry.restore(&window) {
+                warn!(target: "show-state", "Failed to restore window '{label}': {e}");
+            }
+        }
+    }
+
+    if let Some(zoom_state) = app.try_state::<ZoomState>() {
+        let mut zoom_levels = zoom_state.zoom_levels.lock();
+        zoom_levels.clear();
+        zoom_levels.extend(state.zoom_levels.clone());
+    }
+    for (label, level) in &state.zoom_levels {
+        if let Some(webview) = app.get_webview(label) {
+            if let Err(e) = webview.set_zoom(*level) {
+                warn!(target: "show-state", "Failed to set zoom of '{label}': {e}");
+            }
+        }
+    }
+
+    let key_bindings = crate::keyboard::get_transport_key_bindings(app.clone());
+    if let Err(e) = app.emit("transport-key-bindings-changed", key_bindings) {
+        warn!(target: "show-state", "Failed to emit transport-key-bindings-changed: {e}");
+    }
+    app.emit("show-state-imported", ())
+        .map_err(|e| format!("Failed to emit show-state-imported: {e}"))
+}
@@ -0,0 +1,191 @@
+use crate::domain::StartupMetrics;
+use crate::server;
+use crate::store;
+use crate::window::MAIN_WINDOW_LABEL;
+use log::{error, info, warn};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Listener, Manager, WebviewUrl, WebviewWindowBuilder};
+use tauri_plugin_dialog::{DialogExt, MessageDialogButtons, MessageDialogKind};
+
+/// Store key for whether a splash window is shown while the server starts
+pub const STARTUP_SPLASH_KEY: &str = "startupSplash";
+
+/// Label of the splash window
+pub const SPLASH_WINDOW_LABEL: &str = "splash";
+
+/// Seconds to wait for the server before offering to retry or quit
+const SERVER_READY_TIMEOUT_SECS: u64 = 30;
+
+/// Page shown in the splash window; the status line is updated as startup progresses
+const SPLASH_HTML: &str = r#"<!doctype html>
+<html>
+<head>
+<meta charset="utf-8">
+<style>
+  html, body { margin: 0; height: 100%; background: black; color: white; }
+  body { display: flex; flex-direction: column; align-items: center; justify-content: center;
+         font-family: system-ui, sans-serif; cursor: default; user-select: none; }
+  h1 { font-size: 22px; font-weight: 600; margin: 0 0 12px; }
+  p { font-size: 14px; opacity: 0.7; margin: 0; }
+</style>
+</head>
+<body>
+<h1>Church Hub</h1>
+<p id="status">Starting...</p>
+</body>
+</html>"#;
+
+/// Checks if the splash window is enabled (off by default)
+pub fn is_startup_splash_enabled(app: &AppHandle) -> bool {
+    store::get_value(app, STARTUP_SPLASH_KEY).unwrap_or(false)
+}
+
+/// Checks if a splash window is shown while the server starts
+#[tauri::command]
+pub fn get_startup_splash(app: AppHandle) -> bool {
+    is_startup_splash_enabled(&app)
+}
+
+/// Enables or disables the splash window shown while the server starts, from the next launch
+#[tauri::command]
+pub fn set_startup_splash(app: AppHandle, enabled: bool) -> Result<(), String> {
+    info!(target: "startup", "Setting startup splash to {enabled}");
+    store::set_value(&app, STARTUP_SPLASH_KEY, &enabled)
+}
+
+/// Encodes a page as a data URL
+fn data_url(html: &str) -> String {
+    let mut url = String::from("data:text/html;charset=utf-8,");
+    for byte in html.bytes() {
+        if byte.is_ascii_alphanumeric() || b"-._~".contains(&byte) {
+            url.push(byte as char);
+        } else {
+            url.push_str(&format!("%{:02X}", byte));
+        }
+    }
+    url
+}
+
+/// Sets the splash window's status line
+fn set_status(app: &AppHandle, status: &str) {
+    let Some(splash) = app.get_webview_window(SPLASH_WINDOW_LABEL) else {
+        return;
+    };
+    let Ok(status) = serde_json::to_string(status) else {
+        return;
+    };
+    let script = format!(
+        "document.getElementById('status').textContent = {};",
+        status
+    );
+    if let Err(e) = splash.eval(&script) {
+        warn!(target: "startup", "Failed to update splash: {e}");
+    }
+}
+
+/// Opens the splash window
+fn open_splash(app: &AppHandle) -> Result<(), String> {
+    let url = data_url(SPLASH_HTML)
+        .parse()
+        .map_err(|e| format!("Invalid splash URL: {}", e))?;
+    WebviewWindowBuilder::new(app, SPLASH_WINDOW_LABEL, WebviewUrl::External(url))
+        .title("Church Hub")
+        .inner_size(360.0, 200.0)
+        .resizable(false)
+        .decorations(false)
+        .center()
+        .background_color(tauri::window::Color(0, 0, 0, 255))
+        .build()
+        .map_err(|e| format!("Failed to open splash window: {}", e))?;
+    Ok(())
+}
+
+/// Asks whether to restart the server after it failed to start. Returns true to retry.
+async fn ask_retry(app: &AppHandle, error: String) -> bool {
+    let dialog = app
+        .dialog()
+        .message(format!(
+            "The Church Hub server did not start.\n\n{}\n\nWould you like to try again?",
+            error
+        ))
+        .title("Server Not Responding")
+        .kind(MessageDialogKind::Error)
+        .buttons(MessageDialogButtons::OkCancelCustom(
+            "Retry".to_string(),
+            "Quit".to_string(),
+        ));
+    tokio::task::spawn_blocking(move || dialog.blocking_show())
+        .await
+        .unwrap_or(false)
+}
+
+/// Shows a splash window while the server starts instead of blocking startup, then shows
+/// the main window once the server answers. The splash says the server is still starting
+/// once it takes longer than usual; if it never answers, the user can restart it or quit.
+/// Returns false if the splash can't be opened, leaving the caller to wait for the server.
+pub fn wait_for_server_with_splash(app: &AppHandle, server_port: u16) -> bool {
+    let main_window = app.get_webview_window(MAIN_WINDOW_LABEL);
+    if let Err(e) = open_splash(app) {
+        warn!(target: "startup", "{e}");
+        return false;
+    }
+    if let Some(main_window) = &main_window {
+        if let Err(e) = main_window.hide() {
+            warn!(target: "startup", "Failed to hide main window: {e}");
+        }
+    }
+
+    let progress_app = app.clone();
+    let progress_listener = app.listen("server-progress", move |event| {
+        let slow = serde_json::from_str::<serde_json::Value>(event.payload())
+            .ok()
+            .and_then(|progress| progress.get("slow").and_then(|slow| slow.as_bool()))
+            .unwrap_or(false);
+        if slow {
+            set_status(&progress_app, "Still starting...");
+        }
+    });
+
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        let t = Instant::now();
+        loop {
+            set_status(&app, "Starting...");
+            let result =
+                server::wait_for_server_startup(&app, server_port, SERVER_READY_TIMEOUT_SECS).await;
+            let Err(err) = result else {
+                break;
+            };
+            error!(target: "sidecar", "{err}");
+            set_status(&app, "The server did not start");
+            if !ask_retry(&app, err).await {
+                info!(target: "startup", "User chose to quit");
+                app.exit(1);
+                return;
+            }
+
+            info!(target: "startup", "Restarting the server");
+            if let Err(e) = server::shutdown_server(&app) {
+                warn!(target: "sidecar", "Failed to stop the server: {e}");
+            }
+            tokio::time::sleep(Duration::from_millis(500)).await;
+            if let Err(e) = server::start_server(&app, server_port) {
+                error!(target: "sidecar", "Failed to start the server: {e}");
+            }
+        }
+        app.unlisten(progress_listener);
+
+        if let Some(metrics) = app.try_state::<Arc<StartupMetrics>>() {
+            metrics.record("server_ready_wait", t.elapsed());
+        }
+        if let Some(main_window) = app.get_webview_window(MAIN_WINDOW_LABEL) {
+            let _ = main_window.show();
+            let _ = main_window.set_focus();
+        }
+        if let Some(splash) = app.get_webview_window(SPLASH_WINDOW_LABEL) {
+            let _ = splash.close();
+        }
+    });
+    true
+}
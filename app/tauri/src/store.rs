@@ -0,0 +1,123 @@
+use log::warn;
+use parking_lot::Mutex;
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::{Map, Value};
+use std::path::{Path, PathBuf};
+use tauri::{AppHandle, Manager};
+
+/// File in the app data directory holding persisted native app state
+pub const STORE_FILE: &str = "app-store.json";
+
+/// Serializes read-modify-write cycles on the store file
+static STORE_LOCK: Mutex<()> = Mutex::new(());
+
+/// Gets the path of the store file inside the app data directory
+pub fn store_path(app: &AppHandle) -> Result<PathBuf, String> {
+    app.path()
+        .app_data_dir()
+        .map(|dir| dir.join(STORE_FILE))
+        .map_err(|e| format!("Failed to resolve app data directory: {e}"))
+}
+
+/// Reads the whole store, treating a missing or corrupt file as empty
+fn read_store(app: &AppHandle) -> Map<String, Value> {
+    match store_path(app) {
+        Ok(path) => read_store_at(&path),
+        Err(_) => Map::new(),
+    }
+}
+
+/// Reads a store file, treating a missing or corrupt file as empty
+fn read_store_at(path: &Path) -> Map<String, Value> {
+    let Ok(contents) = std::fs::read_to_string(path) else {
+        return Map::new();
+    };
+    match serde_json::from_str::<Map<String, Value>>(&contents) {
+        Ok(map) => map,
+        Err(e) => {
+            warn!(target: "store", "Ignoring corrupt store file {path:?}: {e}");
+            Map::new()
+        }
+    }
+}
+
+/// Writes the whole store (via a temp file so a crash can't truncate it)
+fn write_store(app: &AppHandle, store: &Map<String, Value>) -> Result<(), String> {
+    let path = store_path(app)?;
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)
+            .map_err(|e| format!("Failed to create app data directory: {e}"))?;
+    }
+
+    let contents = serde_json::to_string_pretty(store)
+        .map_err(|e| format!("Failed to serialize store: {e}"))?;
+    let tmp_path = path.with_extension("json.tmp");
+    std::fs::write(&tmp_path, contents).map_err(|e| format!("Failed to write store: {e}"))?;
+    std::fs::rename(&tmp_path, &path).map_err(|e| format!("Failed to write store: {e}"))
+}
+
+/// Gets a value from the store, returning None if missing or of the wrong shape
+pub fn get_value<T: DeserializeOwned>(app: &AppHandle, key: &str) -> Option<T> {
+    let _guard = STORE_LOCK.lock();
+    read_store(app)
+        .remove(key)
+        .and_then(|value| serde_json::from_value(value).ok())
+}
+
+/// Gets every value in the store
+pub fn read_all(app: &AppHandle) -> Map<String, Value> {
+    let _guard = STORE_LOCK.lock();
+    read_store(app)
+}
+
+/// Gets a value from a store file by path, for use before the app is built
+/// (e.g. settings that must be applied before the first webview is created)
+pub fn get_value_at<T: DeserializeOwned>(path: &Path, key: &str) -> Option<T> {
+    let _guard = STORE_LOCK.lock();
+    read_store_at(path)
+        .remove(key)
+        .and_then(|value| serde_json::from_value(value).ok())
+}
+
+/// Sets a value in the store
+pub fn set_value<T: Serialize>(app: &AppHandle, key: &str, value: &T) -> Result<(), String> {
+    let value =
+        serde_json::to_value(value).map_err(|e| format!("Failed to serialize '{key}': {e}"))?;
+
+    let _guard = STORE_LOCK.lock();
+    let mut store = read_store(app);
+    store.insert(key.to_string(), value);
+    write_store(app, &store)
+}
+
+/// Updates the whole store in one step (e.g. to replace it with an imported copy)
+pub fn update_all(
+    app: &AppHandle,
+    update: impl FnOnce(&mut Map<String, Value>),
+) -> Result<(), String> {
+    let _guard = STORE_LOCK.lock();
+    let mut store = read_store(app);
+    update(&mut store);
+    write_store(app, &store)
+}
+
+/// Removes a value from the store
+pub fn remove_value(app: &AppHandle, key: &str) -> Result<(), String> {
+    let _guard = STORE_LOCK.lock();
+    let mut store = read_store(app);
+    if store.remove(key).is_some() {
+        write_store(app, &store)?;
+    }
+    Ok(())
+}
+
+/// Deletes the store file, resetting every persisted value
+pub fn clear(app: &AppHandle) -> Result<(), String> {
+    let path = store_path(app)?;
+    let _guard = STORE_LOCK.lock();
+    match std::fs::remove_file(&path) {
+        Ok(()) => Ok(()),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+        Err(e) => Err(format!("Failed to delete store: {e}")),
+    }
+}
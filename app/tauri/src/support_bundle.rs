@@ -0,0 +1,239 @@
+use crate::diagnostics::{collect_diagnostics, redact_user_paths};
+use crate::domain::{AppState, StartupMetrics};
+use crate::store;
+use log::info;
+use serde::Serialize;
+use serde_json::{Map, Value};
+use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
+use tauri::{AppHandle, Manager};
+
+/// Parts of setting and variable names whose values are replaced in the bundle
+const SENSITIVE_NAME_PARTS: &[&str] = &["secret", "token", "password", "jwt", "credential"];
+
+/// Replaces redacted values
+const REDACTED: &str = "[redacted]";
+
+/// Checks if a setting or variable name looks like it holds a secret
+fn is_sensitive_name(name: &str) -> bool {
+    let name = name.to_ascii_lowercase();
+    SENSITIVE_NAME_PARTS.iter().any(|part| name.contains(part))
+}
+
+/// Replaces the values of sensitive keys anywhere in a JSON value, collecting the
+/// replaced strings so they can also be scrubbed from logs
+fn redact_secrets(value: &mut Value, secrets: &mut Vec<String>) {
+    match value {
+        Value::Object(map) => {
+            for (key, value) in map.iter_mut() {
+                if is_sensitive_name(key) {
+                    if let Value::String(secret) = value {
+                        secrets.push(secret.clone());
+                    }
+                    *value = Value::String(REDACTED.to_string());
+                } else {
+                    redact_secrets(value, secrets);
+                }
+            }
+        }
+        Value::Array(values) => values
+            .iter_mut()
+            .for_each(|value| redact_secrets(value, secrets)),
+        _ => {}
+    }
+}
+
+/// Days since 1970-01-01 to a (year, month, day) civil date
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+/// A UTC timestamp split into date and time fields
+struct Timestamp {
+    year: i64,
+    month: u32,
+    day: u32,
+    hour: u32,
+    minute: u32,
+    second: u32,
+}
+
+impl Timestamp {
+    fn now() -> Self {
+        let secs = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs() as i64)
+            .unwrap_or_default();
+        let (year, month, day) = civil_from_days(secs.div_euclid(86_400));
+        let time = secs.rem_euclid(86_400) as u32;
+        Timestamp {
+            year,
+            month,
+            day,
+            hour: time / 3600,
+            minute: time / 60 % 60,
+            second: time % 60,
+        }
+    }
+
+    /// MS-DOS date and time, as stored in zip headers
+    fn dos_date_time(&self) -> (u16, u16) {
+        let year = self.year.clamp(1980, 2107) as u16 - 1980;
+        let date = (year << 9) | ((self.month as u16) << 5) | self.day as u16;
+        let time =
+            ((self.hour as u16) << 11) | ((self.minute as u16) << 5) | (self.second as u16 / 2);
+        (date, time)
+    }
+}
+
+/// CRC-32 (IEEE) of a buffer, as required by zip entries
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for byte in data {
+        crc ^= u32::from(*byte);
+        for _ in 0..8 {
+            crc = if crc & 1 != 0 {
+                (crc >> 1) ^ 0xEDB8_8320
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    !crc
+}
+
+/// Serializes a bundle file as pretty JSON, scrubbed with `scrub`
+fn to_json<T: Serialize>(value: &T, scrub: &impl Fn(String) -> String) -> Result<Vec<u8>, String> {
+    serde_json::to_string_pretty(value)
+        .map(|json| scrub(json).into_bytes())
+        .map_err(|e| format!("Failed to serialize support bundle: {}", e))
+}
+
+/// Builds a zip archive of uncompressed (stored) files. The bundle is small text,
+/// so compression isn't worth a dependency.
+fn build_zip(files: &[(&str, Vec<u8>)], timestamp: &Timestamp) -> Vec<u8> {
+    // Bit 11: file names are UTF-8
+    const FLAGS: u16 = 0x0800;
+    const VERSION: u16 = 20;
+    let (date, time) = timestamp.dos_date_time();
+
+    let mut archive = Vec::new();
+    let mut directory = Vec::new();
+    for (name, data) in files {
+        let offset = archive.len() as u32;
+        let crc = crc32(data);
+        let size = data.len() as u32;
+
+        archive.extend_from_slice(&0x0403_4b50u32.to_le_bytes());
+        for field in [VERSION, FLAGS, 0, time, date] {
+            archive.extend_from_slice(&field.to_le_bytes());
+        }
+        for field in [crc, size, size] {
+            archive.extend_from_slice(&field.to_le_bytes());
+        }
+        archive.extend_from_slice(&(name.len() as u16).to_le_bytes());
+        archive.extend_from_slice(&0u16.to_le_bytes());
+        archive.extend_from_slice(name.as_bytes());
+        archive.extend_from_slice(data);
+
+        directory.extend_from_slice(&0x0201_4b50u32.to_le_bytes());
+        for field in [VERSION, VERSION, FLAGS, 0, time, date] {
+            directory.extend_from_slice(&field.to_le_bytes());
+        }
+        for field in [crc, size, size] {
+            directory.extend_from_slice(&field.to_le_bytes());
+        }
+        // Name length, then no extra field, comment, disk number or attributes
+        for field in [name.len() as u16, 0, 0, 0, 0] {
+            directory.extend_from_slice(&field.to_le_bytes());
+        }
+        directory.extend_from_slice(&0u32.to_le_bytes());
+        directory.extend_from_slice(&offset.to_le_bytes());
+        directory.extend_from_slice(name.as_bytes());
+    }
+
+    let directory_offset = archive.len() as u32;
+    let directory_size = directory.len() as u32;
+    archive.extend_from_slice(&directory);
+    archive.extend_from_slice(&0x0605_4b50u32.to_le_bytes());
+    for field in [0, 0, files.len() as u16, files.len() as u16] {
+        archive.extend_from_slice(&field.to_le_bytes());
+    }
+    archive.extend_from_slice(&directory_size.to_le_bytes());
+    archive.extend_from_slice(&directory_offset.to_le_bytes());
+    archive.extend_from_slice(&0u16.to_le_bytes());
+    archive
+}
+
+/// Exports a zip for support with the full sidecar log, diagnostics, startup metrics,
+/// the persisted settings and the sidecar environment, and returns its path. Values of
+/// settings and variables that look like secrets (e.g. a JWT secret) are redacted, as
+/// is the user's home directory in paths. Audio plays in the webview, so there is no
+/// native audio log to include.
+#[tauri::command]
+pub async fn export_support_bundle(app: AppHandle) -> Result<String, String> {
+    let diagnostics = collect_diagnostics(app.clone()).await?;
+
+    let mut secrets = Vec::new();
+    let mut config = Value::Object(store::read_all(&app));
+    redact_secrets(&mut config, &mut secrets);
+    let mut environment = Value::Object(
+        crate::server::sidecar_environment(&app)
+            .into_iter()
+            .map(|(name, value)| (name, Value::String(value)))
+            .collect::<Map<String, Value>>(),
+    );
+    redact_secrets(&mut environment, &mut secrets);
+    secrets.retain(|secret| !secret.is_empty());
+
+    // Secrets can also appear in logs (e.g. the sidecar environment logged at spawn)
+    let scrub = |text: String| {
+        let text = secrets
+            .iter()
+            .fold(text, |text, secret| text.replace(secret.as_str(), REDACTED));
+        redact_user_paths(&app, &text)
+    };
+
+    let sidecar_log = app
+        .try_state::<AppState>()
+        .map(|state| state.server_logs.lock().iter().cloned().collect::<Vec<_>>())
+        .unwrap_or_default()
+        .join("\n");
+    let startup_metrics = app
+        .try_state::<Arc<StartupMetrics>>()
+        .map(|metrics| metrics.phases.lock().clone())
+        .unwrap_or_default();
+
+    let files = [
+        ("diagnostics.json", to_json(&diagnostics, &scrub)?),
+        ("startup-metrics.json", to_json(&startup_metrics, &scrub)?),
+        ("config.json", to_json(&config, &scrub)?),
+        ("sidecar-environment.json", to_json(&environment, &scrub)?),
+        ("sidecar.log", scrub(sidecar_log).into_bytes()),
+    ];
+
+    let timestamp = Timestamp::now();
+    let path = std::env::temp_dir().join(format!(
+        "church-hub-support-{:04}{:02}{:02}-{:02}{:02}{:02}.zip",
+        timestamp.year,
+        timestamp.month,
+        timestamp.day,
+        timestamp.hour,
+        timestamp.minute,
+        timestamp.second
+    ));
+    std::fs::write(&path, build_zip(&files, &timestamp))
+        .map_err(|e| format!("Failed to write support bundle: {}", e))?;
+
+    info!(target: "diagnostics", "Exported support bundle to {:?}", path);
+    Ok(path.to_string_lossy().to_string())
+}
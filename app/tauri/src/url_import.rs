@@ -0,0 +1,256 @@
+use crate::commands::{forward_file_import, IMPORTABLE_EXTENSIONS};
+use crate::deep_link::URL_SCHEME;
+use log::{info, warn};
+use serde::Serialize;
+use std::io::{self, Read};
+use std::net::{IpAddr, SocketAddr, ToSocketAddrs};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tauri::{AppHandle, Emitter, Url};
+use tauri_plugin_dialog::{DialogExt, MessageDialogButtons, MessageDialogKind};
+
+/// Largest file we'll download for an import
+const MAX_DOWNLOAD_BYTES: u64 = 100 * 1024 * 1024;
+
+/// Timeout for downloading an import file
+const DOWNLOAD_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Payload of the `import-download-failed` event
+#[derive(Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportDownloadFailed {
+    pub url: String,
+    pub error: String,
+}
+
+/// Extracts the file URL from an import link:
+/// `churchhub://import?url=<percent-encoded https URL>[&format=<extension>]`
+pub fn parse_import_link(link: &str) -> Option<(Url, String)> {
+    let link = Url::parse(link).ok()?;
+    if link.scheme() != URL_SCHEME || link.host_str() != Some("import") {
+        return None;
+    }
+
+    let query_value = |name: &str| {
+        link.query_pairs()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.into_owned())
+    };
+    let url = Url::parse(&query_value("url")?).ok()?;
+    if url.scheme() != "https" {
+        return None;
+    }
+
+    // An explicit format wins over the extension in the URL path
+    let format = query_value("format").or_else(|| {
+        Path::new(url.path())
+            .extension()
+            .map(|ext| ext.to_string_lossy().into_owned())
+    })?;
+    let format = IMPORTABLE_EXTENSIONS
+        .iter()
+        .find(|supported| format.eq_ignore_ascii_case(supported))?;
+
+    Some((url, format.to_string()))
+}
+
+/// Picks a temp file name for a download, keeping the URL's file name when it has the right extension
+fn download_path(url: &Url, format: &str) -> PathBuf {
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis())
+        .unwrap_or_default();
+    let file_name = url
+        .path_segments()
+        .and_then(|mut segments| segments.next_back())
+        .filter(|name| {
+            Path::new(name)
+                .extension()
+                .is_some_and(|ext| ext.eq_ignore_ascii_case(format))
+        })
+        .map(|name| name.to_string())
+        .unwrap_or_else(|| format!("import.{format}"));
+
+    std::env::temp_dir()
+        .join("church-hub-imports")
+        .join(format!("{timestamp}-{file_name}"))
+}
+
+/// Checks if an address is on the public internet, so a link can't make the app fetch
+/// from the local sidecar or from other machines on the venue network
+fn is_public_address(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => {
+            let [first, second, ..] = ip.octets();
+            !(ip.is_private()
+                || ip.is_loopback()
+                || ip.is_link_local()
+                || ip.is_unspecified()
+                || ip.is_broadcast()
+                || ip.is_multicast()
+                || ip.is_documentation()
+                || first == 0
+                // Carrier-grade NAT (100.64.0.0/10)
+                || (first == 100 && second & 0xc0 == 64))
+        }
+        IpAddr::V6(ip) => match ip.to_ipv4_mapped() {
+            Some(ip) => is_public_address(IpAddr::V4(ip)),
+            None => {
+                let first = ip.segments()[0];
+                !(ip.is_loopback()
+                    || ip.is_unspecified()
+                    || ip.is_multicast()
+                    // Unique local (fc00::/7) and link-local (fe80::/10)
+                    || first & 0xfe00 == 0xfc00
+                    || first & 0xffc0 == 0xfe80)
+            }
+        },
+    }
+}
+
+/// Resolves a download host (including redirect targets), refusing hosts with
+/// non-public addresses
+fn resolve_public(netloc: &str) -> io::Result<Vec<SocketAddr>> {
+    let addresses: Vec<SocketAddr> = netloc.to_socket_addrs()?.collect();
+    if addresses
+        .iter()
+        .any(|address| !is_public_address(address.ip()))
+    {
+        return Err(io::Error::new(
+            io::ErrorKind::PermissionDenied,
+            format!("{netloc} is not a public address"),
+        ));
+    }
+    Ok(addresses)
+}
+
+/// Downloads a file to the given path over https from a public host, refusing anything
+/// larger than the size limit
+fn download(url: &Url, path: &Path) -> Result<(), String> {
+    let agent = ureq::AgentBuilder::new()
+        .timeout(DOWNLOAD_TIMEOUT)
+        .https_only(true)
+        .resolver(resolve_public)
+        .build();
+    let response = agent
+        .get(url.as_str())
+        .call()
+        .map_err(|e| format!("Failed to download file: {e}"))?;
+
+    let mut contents = Vec::new();
+    response
+        .into_reader()
+        .take(MAX_DOWNLOAD_BYTES + 1)
+        .read_to_end(&mut contents)
+        .map_err(|e| format!("Failed to download file: {e}"))?;
+    if contents.len() as u64 > MAX_DOWNLOAD_BYTES {
+        return Err(format!(
+            "File is larger than {} MB",
+            MAX_DOWNLOAD_BYTES / 1024 / 1024
+        ));
+    }
+
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)
+            .map_err(|e| format!("Failed to create download directory: {e}"))?;
+    }
+    std::fs::write(path, contents).map_err(|e| format!("Failed to save downloaded file: {e}"))
+}
+
+/// Asks the user whether to download a linked file, since any web page can open an
+/// import link. Returns true to download.
+async fn confirm_download(app: &AppHandle, url: &Url) -> bool {
+    let dialog = app
+        .dialog()
+        .message(format!(
+            "A link asked Church Hub to download and import a file from {}:\n\n{}\n\nOnly continue if you trust this source.",
+            url.host_str().unwrap_or_default(),
+            url
+        ))
+        .title("Import File from Link")
+        .kind(MessageDialogKind::Warning)
+        .buttons(MessageDialogButtons::OkCancelCustom(
+            "Import".to_string(),
+            "Cancel".to_string(),
+        ));
+    tokio::task::spawn_blocking(move || dialog.blocking_show())
+        .await
+        .unwrap_or(false)
+}
+
+/// Downloads a linked file in the background once the user confirms, and routes it through
+/// the pending import flow. Failures are emitted as `import-download-failed`.
+pub fn import_from_url(app: &AppHandle, url: Url, format: String) {
+    let app = app.clone();
+
+    tauri::async_runtime::spawn(async move {
+        if !confirm_download(&app, &url).await {
+            info!(target: "url-import", "Import of {url} declined");
+            return;
+        }
+        info!(target: "url-import", "Downloading {url}");
+
+        let path = download_path(&url, &format);
+        let download_url = url.clone();
+        let result = tokio::task::spawn_blocking(move || {
+            download(&download_url, &path)?;
+            Ok::<_, String>(path)
+        })
+        .await
+        .unwrap_or_else(|e| Err(format!("Download task failed: {e}")));
+
+        match result {
+            Ok(path) => {
+                info!(target: "url-import", "Downloaded {url} to {path:?}");
+                forward_file_import(&app, path);
+            }
+            Err(error) => {
+                warn!(target: "url-import", "{error}");
+                let payload = ImportDownloadFailed {
+                    url: url.to_string(),
+                    error,
+                };
+                if let Err(e) = app.emit("import-download-failed", payload) {
+                    warn!(target: "url-import", "Failed to emit import-download-failed: {e}");
+                }
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn import_links_require_https() {
+        let (url, format) =
+            parse_import_link("churchhub://import?url=https%3A%2F%2Fexample.com%2Fsong.opensong")
+                .unwrap();
+        assert_eq!(url.as_str(), "https://example.com/song.opensong");
+        assert_eq!(format, "opensong");
+        assert!(
+            parse_import_link("churchhub://import?url=http%3A%2F%2Fexample.com%2Fa.pptx").is_none()
+        );
+    }
+
+    #[test]
+    fn private_addresses_are_rejected() {
+        for ip in [
+            "127.0.0.1",
+            "10.0.0.5",
+            "192.168.1.10",
+            "169.254.1.1",
+            "100.64.0.1",
+            "0.0.0.0",
+            "::1",
+            "fd00::1",
+            "fe80::1",
+            "::ffff:127.0.0.1",
+        ] {
+            assert!(!is_public_address(ip.parse().unwrap()), "{ip}");
+        }
+        assert!(is_public_address("93.184.216.34".parse().unwrap()));
+        assert!(is_public_address("2606:4700::1111".parse().unwrap()));
+    }
+}
@@ -0,0 +1,200 @@
+use crate::store;
+use log::{info, warn};
+use std::process::Command;
+use tauri::{AppHandle, Emitter};
+
+/// Maximum system output volume level
+const MAX_VOLUME: u32 = 100;
+
+/// Store key for the venue volume ceiling (0.0-1.0)
+pub const VOLUME_LIMIT_KEY: &str = "volumeLimit";
+
+/// Runs a command and returns its trimmed stdout, failing on a non-zero exit status
+fn run(program: &str, args: &[&str]) -> Result<String, String> {
+    let output = Command::new(program)
+        .args(args)
+        .output()
+        .map_err(|e| format!("Failed to run {}: {}", program, e))?;
+
+    if !output.status.success() {
+        return Err(format!(
+            "{} failed: {}",
+            program,
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// Parses a volume level reported by a platform tool
+fn parse_level(text: &str) -> Result<u32, String> {
+    text.trim()
+        .parse::<f64>()
+        .map(|level| (level.round() as u32).min(MAX_VOLUME))
+        .map_err(|_| format!("Unexpected volume output: '{}'", text))
+}
+
+#[cfg(target_os = "macos")]
+fn read_system_volume() -> Result<u32, String> {
+    let output = run(
+        "osascript",
+        &["-e", "output volume of (get volume settings)"],
+    )?;
+    // Reports "missing value" for outputs without a software volume (e.g. some HDMI
+    // devices), which play at whatever level the device itself is set to
+    if output == "missing value" {
+        return Ok(MAX_VOLUME);
+    }
+    parse_level(&output)
+}
+
+#[cfg(target_os = "macos")]
+fn write_system_volume(level: u32) -> Result<(), String> {
+    run(
+        "osascript",
+        &["-e", &format!("set volume output volume {}", level)],
+    )
+    .map(|_| ())
+}
+
+// Minimal Core Audio interop for the default output endpoint, since Windows ships no
+// command line tool for the master volume
+#[cfg(target_os = "windows")]
+const WINDOWS_VOLUME_TYPE: &str = r#"
+Add-Type -TypeDefinition @'
+using System.Runtime.InteropServices;
+[Guid("5CDF2C82-841E-4546-9722-0CF74078229A"), InterfaceType(ComInterfaceType.InterfaceIsIUnknown)]
+interface IAudioEndpointVolume {
+  int f(); int g(); int h(); int i();
+  int SetMasterVolumeLevelScalar(float fLevel, System.Guid pguidEventContext);
+  int j();
+  int GetMasterVolumeLevelScalar(out float pfLevel);
+}
+[Guid("D666063F-1587-4E43-81F1-B948E807363F"), InterfaceType(ComInterfaceType.InterfaceIsIUnknown)]
+interface IMMDevice {
+  int Activate(ref System.Guid id, int clsCtx, int activationParams, out IAudioEndpointVolume aev);
+}
+[Guid("A95664D2-9614-4F35-A746-DE8DB63617E6"), InterfaceType(ComInterfaceType.InterfaceIsIUnknown)]
+interface IMMDeviceEnumerator {
+  int f();
+  int GetDefaultAudioEndpoint(int dataFlow, int role, out IMMDevice endpoint);
+}
+[ComImport, Guid("BCDE0395-E52F-467C-8E3D-C4579291692E")] class MMDeviceEnumeratorComObject { }
+public class ChurchHubVolume {
+  static IAudioEndpointVolume Endpoint() {
+    var enumerator = new MMDeviceEnumeratorComObject() as IMMDeviceEnumerator;
+    IMMDevice device = null;
+    Marshal.ThrowExceptionForHR(enumerator.GetDefaultAudioEndpoint(0, 1, out device));
+    IAudioEndpointVolume volume = null;
+    var id = typeof(IAudioEndpointVolume).GUID;
+    Marshal.ThrowExceptionForHR(device.Activate(ref id, 23, 0, out volume));
+    return volume;
+  }
+  public static float Level {
+    get { float level = 0; Marshal.ThrowExceptionForHR(Endpoint().GetMasterVolumeLevelScalar(out level)); return level; }
+    set { Marshal.ThrowExceptionForHR(Endpoint().SetMasterVolumeLevelScalar(value, System.Guid.Empty)); }
+  }
+}
+'@
+"#;
+
+#[cfg(target_os = "windows")]
+fn read_system_volume() -> Result<u32, String> {
+    let script = format!(
+        "{}[math]::Round([ChurchHubVolume]::Level * 100)",
+        WINDOWS_VOLUME_TYPE
+    );
+    parse_level(&run("powershell", &["-NoProfile", "-Command", &script])?)
+}
+
+#[cfg(target_os = "windows")]
+fn write_system_volume(level: u32) -> Result<(), String> {
+    let script = format!(
+        "{}[ChurchHubVolume]::Level = {}",
+        WINDOWS_VOLUME_TYPE,
+        level as f32 / MAX_VOLUME as f32
+    );
+    run("powershell", &["-NoProfile", "-Command", &script]).map(|_| ())
+}
+
+#[cfg(target_os = "linux")]
+fn read_system_volume() -> Result<u32, String> {
+    // "Volume: front-left: 42597 /  65% / -11.23 dB,   front-right: ..."
+    let output = run("pactl", &["get-sink-volume", "@DEFAULT_SINK@"])?;
+    let percent = output
+        .split_whitespace()
+        .find_map(|part| part.strip_suffix('%'))
+        .ok_or_else(|| format!("Unexpected pactl output: '{}'", output))?;
+    parse_level(percent)
+}
+
+#[cfg(target_os = "linux")]
+fn write_system_volume(level: u32) -> Result<(), String> {
+    run(
+        "pactl",
+        &["set-sink-volume", "@DEFAULT_SINK@", &format!("{}%", level)],
+    )
+    .map(|_| ())
+}
+
+/// Gets the OS master output volume (0-100)
+#[tauri::command]
+pub async fn get_system_volume() -> Result<u32, String> {
+    tokio::task::spawn_blocking(read_system_volume)
+        .await
+        .map_err(|e| format!("Volume task failed: {}", e))?
+}
+
+/// Gets the volume ceiling as a fraction of full volume (1.0 when no limit is set)
+#[tauri::command]
+pub fn get_volume_limit(app: AppHandle) -> f64 {
+    store::get_value(&app, VOLUME_LIMIT_KEY).unwrap_or(1.0)
+}
+
+/// Sets a volume ceiling (0.0-1.0) protecting the PA and listeners. The system volume
+/// is lowered to it if currently louder and never set above it afterwards, and the
+/// frontend caps its playback volume slider to it. Failing to adjust the system volume
+/// (e.g. no audio output) is only logged, since the limit still applies.
+#[tauri::command]
+pub async fn set_volume_limit(app: AppHandle, max: f64) -> Result<(), String> {
+    if !(0.0..=1.0).contains(&max) {
+        return Err(format!("Volume limit must be between 0 and 1, got {}", max));
+    }
+    info!(target: "volume", "Setting volume limit to {}", max);
+    store::set_value(&app, VOLUME_LIMIT_KEY, &max)?;
+    app.emit("volume-limit-changed", max)
+        .map_err(|e| format!("Failed to emit volume-limit-changed: {}", e))?;
+
+    let limit = (max * MAX_VOLUME as f64).floor() as u32;
+    let lowered = tokio::task::spawn_blocking(move || {
+        let level = read_system_volume()?;
+        if level > limit {
+            info!(target: "volume", "Lowering system volume from {} to the limit", level);
+            write_system_volume(limit)?;
+        }
+        Ok::<_, String>(())
+    })
+    .await
+    .map_err(|e| format!("Volume task failed: {}", e))?;
+    if let Err(e) = lowered {
+        warn!(target: "volume", "Failed to apply the volume limit to the system volume: {}", e);
+    }
+    Ok(())
+}
+
+/// Sets the OS master output volume, clamped to 0-100 and the volume limit, and returns
+/// the resulting level. This affects every app on the machine, unlike the app's own
+/// playback volume.
+#[tauri::command]
+pub async fn set_system_volume(app: AppHandle, level: u32) -> Result<u32, String> {
+    let limit = (get_volume_limit(app) * MAX_VOLUME as f64).floor() as u32;
+    let level = level.min(limit).min(MAX_VOLUME);
+    info!(target: "volume", "Setting system volume to {}", level);
+
+    tokio::task::spawn_blocking(move || {
+        write_system_volume(level)?;
+        read_system_volume()
+    })
+    .await
+    .map_err(|e| format!("Volume task failed: {}", e))?
+}
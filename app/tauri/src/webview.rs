@@ -1,6 +1,20 @@
-use std::time::Duration;
-use tauri::{webview::WebviewBuilder, LogicalPosition, LogicalSize, Manager, WebviewUrl};
+use crate::display::is_display_window;
+use crate::error::WebviewError;
+use crate::store;
+use crate::window::MAIN_WINDOW_LABEL;
+use log::{info, warn};
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tauri::{
+    webview::{PageLoadEvent, WebviewBuilder},
+    Emitter, Listener, LogicalPosition, LogicalSize, Manager, WebviewUrl,
+};
 use tauri_utils::config::BackgroundThrottlingPolicy;
+use tokio::sync::Notify;
 use tokio::time::sleep;
 
 // Modern Chrome user agents for compatibility with sites like YouTube and WhatsApp Web
@@ -14,49 +28,231 @@ const CHROME_USER_AGENT: &str = "Mozilla/5.0 (Windows NT 10.0; Win64; x64) Apple
 #[cfg(target_os = "linux")]
 const CHROME_USER_AGENT: &str = "Mozilla/5.0 (X11; Linux x86_64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/143.0.0.0 Safari/537.36";
 
-// Maximum retries for getting main window (handles timing issues during startup)
+// DOM event dispatched inside a child webview for messages from the control UI
+const MESSAGE_EVENT: &str = "church-hub-message";
+
+// Exposes `window.churchHub.postMessage(message)` to child webviews so pages served
+// by the app (e.g. lyrics) can send messages back to the control UI
+const MESSAGE_BRIDGE_SCRIPT: &str = r#"
+window.churchHub = window.churchHub || {
+  postMessage: function (message) {
+    if (!window.__TAURI_INTERNALS__) return Promise.reject(new Error('Tauri IPC unavailable'));
+    return window.__TAURI_INTERNALS__.invoke('webview_send_message', { message: message });
+  }
+};
+"#;
+
+// How long a call waits for a concurrent creation of the same label before giving up
+const CREATE_WAIT_TIMEOUT: Duration = Duration::from_secs(5);
+
+// Page a suspended webview is parked on
+const SUSPENDED_URL: &str = "about:blank";
+
+/// State of a child webview before it was suspended
+struct SuspendedWebview {
+    url: tauri::Url,
+    hidden: bool,
+}
+
+// Position and size of a child webview
+type WebviewBounds = (LogicalPosition<f64>, LogicalSize<f64>);
+
+/// What the app tracks about its child webviews (the platform webviews can't be queried)
+#[derive(Default)]
+pub struct WebviewRegistry {
+    /// Labels of child webviews currently being created
+    creating: Mutex<BTreeSet<String>>,
+    /// Notified whenever a label is removed from `creating`
+    create_finished: Notify,
+    /// Labels of child webviews currently hidden
+    hidden: Mutex<BTreeSet<String>>,
+    /// Child webviews unloaded by suspend_child_webview, with what resuming restores
+    suspended: Mutex<BTreeMap<String, SuspendedWebview>>,
+    /// Latest requested bounds per webview, waiting to be applied by update_child_webview
+    pending_bounds: Mutex<BTreeMap<String, WebviewBounds>>,
+    /// Settings applied to child webviews after creation, by label. Kept when a webview
+    /// is closed so a recreated webview with the same label picks them up.
+    live_config: Mutex<BTreeMap<String, ChildWebviewConfig>>,
+    /// Cached copy of the default options, loaded from the store on first use
+    default_options: Mutex<Option<DefaultWebviewOptions>>,
+}
+
+impl WebviewRegistry {
+    /// Checks if a child webview was hidden with `hide_child_webview` or created hidden
+    pub fn is_hidden(&self, label: &str) -> bool {
+        self.hidden.lock().contains(label)
+    }
+
+    /// Drops the cached default options so they are read from the store again; call
+    /// after the store changed other than through `set_default_webview_options`
+    pub fn forget_default_options(&self) {
+        *self.default_options.lock() = None;
+    }
+
+    /// Drops what is tracked about a closed webview, except its settings
+    fn forget(&self, label: &str) {
+        self.hidden.lock().remove(label);
+        self.suspended.lock().remove(label);
+        self.pending_bounds.lock().remove(label);
+    }
+}
+
+/// Marks a label as being created until dropped
+struct CreatingGuard<'a> {
+    registry: &'a WebviewRegistry,
+    label: String,
+}
+
+impl<'a> CreatingGuard<'a> {
+    /// Claims a label, returning None if another call is already creating it
+    fn try_acquire(registry: &'a WebviewRegistry, label: &str) -> Option<Self> {
+        registry
+            .creating
+            .lock()
+            .insert(label.to_string())
+            .then(|| CreatingGuard {
+                registry,
+                label: label.to_string(),
+            })
+    }
+
+    /// Claims a label, waiting for any in-flight creation of it to finish first
+    async fn acquire(registry: &'a WebviewRegistry, label: &str) -> Result<Self, WebviewError> {
+        let started = Instant::now();
+        loop {
+            // Register for the notification before checking so a release can't be missed
+            let finished = registry.create_finished.notified();
+            tokio::pin!(finished);
+            finished.as_mut().enable();
+
+            if let Some(guard) = Self::try_acquire(registry, label) {
+                return Ok(guard);
+            }
+
+            info!(target: "webview", "Webview '{}' is being created, waiting...", label);
+            let remaining = CREATE_WAIT_TIMEOUT.saturating_sub(started.elapsed());
+            if tokio::time::timeout(remaining, finished).await.is_err() {
+                return Err(WebviewError::AlreadyCreating(label.to_string()));
+            }
+        }
+    }
+}
+
+impl Drop for CreatingGuard<'_> {
+    fn drop(&mut self) {
+        self.registry.creating.lock().remove(&self.label);
+        self.registry.create_finished.notify_waiters();
+    }
+}
+
+// Window in which rapid update_child_webview calls are coalesced (about one frame)
+const UPDATE_COALESCE_WINDOW: Duration = Duration::from_millis(16);
+
+// Defaults for waiting on the main window (handles timing issues during startup),
+// overridable with CHURCH_HUB_MAIN_WINDOW_RETRIES / CHURCH_HUB_MAIN_WINDOW_RETRY_DELAY_MS
 const MAX_MAIN_WINDOW_RETRIES: u32 = 10;
 const RETRY_DELAY_MS: u64 = 200;
 
-/// Helper function to get the main window with retries
+// Upper bounds for the overrides, keeping the wait under a few minutes
+const MAIN_WINDOW_RETRIES_LIMIT: u32 = 100;
+const RETRY_DELAY_LIMIT_MS: u64 = 5_000;
+
+/// Reads a positive number from an environment variable, capped at `max` and falling
+/// back to a default
+fn env_or_default<T: std::str::FromStr + PartialOrd + Default>(
+    name: &str,
+    default: T,
+    max: T,
+) -> T {
+    let value = std::env::var(name)
+        .ok()
+        .and_then(|value| value.trim().parse::<T>().ok())
+        .filter(|value| *value > T::default())
+        .unwrap_or(default);
+    if value > max {
+        max
+    } else {
+        value
+    }
+}
+
+/// Waits for the main window, waking as soon as a webview is created and polling
+/// every retry delay as a fallback. Fails with a `timeout` error the frontend can retry.
 async fn get_main_window_with_retry(
     app: &tauri::AppHandle,
-) -> Result<tauri::WebviewWindow, String> {
-    for attempt in 1..=MAX_MAIN_WINDOW_RETRIES {
-        // List all available windows for debugging
-        let windows = app.webview_windows();
-        let window_labels: Vec<_> = windows.keys().collect();
-        println!(
-            "[webview] Attempt {}/{}: Available windows: {:?}",
-            attempt, MAX_MAIN_WINDOW_RETRIES, window_labels
-        );
+) -> Result<tauri::WebviewWindow, WebviewError> {
+    if let Some(window) = app.get_webview_window("main") {
+        return Ok(window);
+    }
+
+    let retries = env_or_default(
+        "CHURCH_HUB_MAIN_WINDOW_RETRIES",
+        MAX_MAIN_WINDOW_RETRIES,
+        MAIN_WINDOW_RETRIES_LIMIT,
+    );
+    let retry_delay = Duration::from_millis(env_or_default(
+        "CHURCH_HUB_MAIN_WINDOW_RETRY_DELAY_MS",
+        RETRY_DELAY_MS,
+        RETRY_DELAY_LIMIT_MS,
+    ));
+    let timeout = retry_delay.saturating_mul(retries);
+
+    let created = Arc::new(Notify::new());
+    let listener = {
+        let created = created.clone();
+        app.listen_any("tauri://webview-created", move |_| created.notify_one())
+    };
 
+    let started = Instant::now();
+    let result = loop {
         if let Some(window) = app.get_webview_window("main") {
-            println!("[webview] Found main window on attempt {}", attempt);
-            return Ok(window);
+            info!(target: "webview", "Found main window after {:?}", started.elapsed());
+            break Ok(window);
         }
 
-        if attempt < MAX_MAIN_WINDOW_RETRIES {
-            println!(
-                "[webview] Main window not found, retry {}/{}...",
-                attempt, MAX_MAIN_WINDOW_RETRIES
+        let remaining = timeout.saturating_sub(started.elapsed());
+        if remaining.is_zero() {
+            // Final debug: list all windows
+            let windows = app.webview_windows();
+            let window_labels: Vec<_> = windows.keys().collect();
+            warn!(
+                target: "webview",
+                "Main window not found after {:?}. Available windows: {:?}",
+                timeout, window_labels
             );
-            sleep(Duration::from_millis(RETRY_DELAY_MS)).await;
+            break Err(WebviewError::Timeout {
+                label: "main".to_string(),
+                waited_ms: timeout.as_millis() as u64,
+            });
         }
-    }
 
-    // Final debug: list all windows
-    let windows = app.webview_windows();
-    let window_labels: Vec<_> = windows.keys().collect();
+        info!(target: "webview", "Main window not found, waiting...");
+        let _ = tokio::time::timeout(remaining.min(retry_delay), created.notified()).await;
+    };
+
+    app.unlisten(listener);
+    result
+}
 
-    Err(format!(
-        "Main window not found after {} retries. Available windows: {:?}",
-        MAX_MAIN_WINDOW_RETRIES, window_labels
-    ))
+/// Payload of the `webview-create-failed` event
+#[derive(Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WebviewCreateFailed {
+    pub label: String,
+    pub error: WebviewError,
 }
 
 /// Creates a child webview at a specific position and size
+/// `init_script` runs on every navigation after the document is created but before any
+/// of the page's own scripts, so it can't race the page the way a script evaluated after
+/// load can. It only applies when the webview is created, not when one is reused, and runs
+/// after the default init script set with `set_default_webview_options`.
+/// With `visible: false` the webview is created (or left) hidden at the given bounds, so a
+/// page can load before `show_child_webview` reveals it without flashing on the projector.
+/// Failures are also emitted as `webview-create-failed`, since creation is sometimes
+/// triggered where no caller surfaces the returned error (e.g. auto-open on startup)
 #[tauri::command]
+#[allow(clippy::too_many_arguments)]
 pub async fn create_child_webview(
     app: tauri::AppHandle,
     label: String,
@@ -65,57 +261,529 @@ pub async fn create_child_webview(
     y: f64,
     width: f64,
     height: f64,
-) -> Result<(), String> {
-    println!("[webview] Creating child webview '{}'", label);
-    println!("[webview] URL: {}", url);
-    println!(
-        "[webview] Position: ({}, {}), Size: {}x{}",
-        x, y, width, height
+    init_script: Option<String>,
+    visible: Option<bool>,
+) -> Result<(), WebviewError> {
+    let options = ChildWebviewOptions {
+        init_script,
+        visible: visible.unwrap_or(true),
+    };
+    let result = ensure_child_webview(
+        &app,
+        &label,
+        &url,
+        LogicalPosition::new(x, y),
+        LogicalSize::new(width, height),
+        &options,
+        false,
+    )
+    .await;
+    report_create_failure(&app, &label, &result);
+    result
+}
+
+/// Creates a child webview or, if it already exists, points it at `url` (when different),
+/// repositions it and shows it. Safe to call repeatedly, e.g. from rapid operator cues.
+/// `init_script` behaves as in `create_child_webview`.
+#[tauri::command]
+#[allow(clippy::too_many_arguments)]
+pub async fn ensure_display(
+    app: tauri::AppHandle,
+    label: String,
+    url: String,
+    x: f64,
+    y: f64,
+    width: f64,
+    height: f64,
+    init_script: Option<String>,
+) -> Result<(), WebviewError> {
+    let options = ChildWebviewOptions {
+        init_script,
+        visible: true,
+    };
+    let result = ensure_child_webview(
+        &app,
+        &label,
+        &url,
+        LogicalPosition::new(x, y),
+        LogicalSize::new(width, height),
+        &options,
+        true,
+    )
+    .await;
+    report_create_failure(&app, &label, &result);
+    result
+}
+
+/// Emits `webview-create-failed` if creating a webview failed
+fn report_create_failure(app: &tauri::AppHandle, label: &str, result: &Result<(), WebviewError>) {
+    if let Err(error) = result {
+        warn!(target: "webview", "Failed to create webview '{}': {}", label, error);
+        let payload = WebviewCreateFailed {
+            label: label.to_string(),
+            error: error.clone(),
+        };
+        if let Err(e) = app.emit("webview-create-failed", payload) {
+            warn!(target: "webview", "Failed to emit webview-create-failed: {}", e);
+        }
+    }
+}
+
+/// Parses a child webview URL
+fn parse_webview_url(url: &str) -> Result<tauri::Url, WebviewError> {
+    url.parse::<tauri::Url>()
+        .map_err(|e| WebviewError::InvalidUrl {
+            url: url.to_string(),
+            reason: e.to_string(),
+        })
+}
+
+/// Moves and resizes a webview
+fn place_webview(
+    webview: &tauri::Webview,
+    position: LogicalPosition<f64>,
+    size: LogicalSize<f64>,
+) -> Result<(), WebviewError> {
+    // These bounds supersede any coalesced update still waiting to be applied
+    webview
+        .state::<WebviewRegistry>()
+        .pending_bounds
+        .lock()
+        .remove(webview.label());
+    webview
+        .set_position(position)
+        .map_err(|e| WebviewError::platform("Failed to set position", e))?;
+    webview
+        .set_size(size)
+        .map_err(|e| WebviewError::platform("Failed to set size", e))
+}
+
+/// Moves and resizes a webview, then shows it
+fn show_webview_at(
+    webview: &tauri::Webview,
+    position: LogicalPosition<f64>,
+    size: LogicalSize<f64>,
+) -> Result<(), WebviewError> {
+    place_webview(webview, position, size)?;
+    webview
+        .show()
+        .map_err(|e| WebviewError::platform("Failed to show webview", e))?;
+    webview
+        .state::<WebviewRegistry>()
+        .hidden
+        .lock()
+        .remove(webview.label());
+    Ok(())
+}
+
+/// Options applied when creating a child webview
+struct ChildWebviewOptions {
+    /// Script run before the page's own scripts on every navigation
+    init_script: Option<String>,
+    /// Whether to show the webview, or create/keep it hidden
+    visible: bool,
+}
+
+// Replaces the style element holding CSS injected by configure_child_webview
+const INJECT_CSS_SCRIPT: &str = r#"
+(function () {
+  var style = document.getElementById('church-hub-injected-css');
+  if (!style) {
+    style = document.createElement('style');
+    style.id = 'church-hub-injected-css';
+    (document.head || document.documentElement).appendChild(style);
+  }
+  style.textContent = __CSS__;
+})();
+"#;
+
+/// Settings for `configure_child_webview`; fields left out are unchanged
+#[derive(Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ChildWebviewConfig {
+    /// Hosts navigations are limited to (subdomains included); empty allows all. When set,
+    /// only http(s) URLs on these hosts and `about:blank` can be loaded.
+    pub allowed_hosts: Option<Vec<String>>,
+    /// CSS injected into every page, replacing any previously injected CSS
+    pub css: Option<String>,
+    /// Script run after every page load
+    pub script: Option<String>,
+    /// User agent, which can only be set when the webview is created
+    pub user_agent: Option<String>,
+    /// Use the page's title as the title of a display window (see `title_window`)
+    pub sync_window_title: Option<bool>,
+    /// Display window retitled by `sync_window_title`; defaults to the display window
+    /// hosting the webview. Child webviews live in the main window, so they need this set.
+    pub title_window: Option<String>,
+}
+
+/// Store key for the options every child webview starts from
+pub const DEFAULT_WEBVIEW_OPTIONS_KEY: &str = "defaultWebviewOptions";
+
+/// Options applied to every child webview (e.g. baseline hardening for all projector
+/// webviews). Per-webview settings take precedence field by field: a host allowlist, CSS,
+/// script, user agent or title sync given to `configure_child_webview` replaces the default
+/// one, and the default `init_script` runs before the one passed when creating the webview.
+#[derive(Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct DefaultWebviewOptions {
+    /// Script run before the page's own scripts on every navigation
+    pub init_script: Option<String>,
+    #[serde(flatten)]
+    pub config: ChildWebviewConfig,
+}
+
+/// Gets the default options, reading them from the store the first time
+fn default_options(app: &tauri::AppHandle) -> DefaultWebviewOptions {
+    app.state::<WebviewRegistry>()
+        .default_options
+        .lock()
+        .get_or_insert_with(|| {
+            store::get_value(app, DEFAULT_WEBVIEW_OPTIONS_KEY).unwrap_or_default()
+        })
+        .clone()
+}
+
+/// Gets a webview's settings with the defaults filled in where it has none of its own
+fn effective_config(app: &tauri::AppHandle, label: &str) -> ChildWebviewConfig {
+    let defaults = default_options(app).config;
+    let live = app
+        .state::<WebviewRegistry>()
+        .live_config
+        .lock()
+        .get(label)
+        .cloned()
+        .unwrap_or_default();
+    ChildWebviewConfig {
+        allowed_hosts: live.allowed_hosts.or(defaults.allowed_hosts),
+        css: live.css.or(defaults.css),
+        script: live.script.or(defaults.script),
+        user_agent: live.user_agent.or(defaults.user_agent),
+        sync_window_title: live.sync_window_title.or(defaults.sync_window_title),
+        title_window: live.title_window.or(defaults.title_window),
+    }
+}
+
+/// Gets the options applied to every child webview
+#[tauri::command]
+pub fn get_default_webview_options(app: tauri::AppHandle) -> DefaultWebviewOptions {
+    default_options(&app)
+}
+
+/// Sets the options applied to every child webview, replacing the previous defaults.
+/// The host allowlist, CSS and script take effect on the next navigation or page load of
+/// existing webviews; the init script and user agent only apply to webviews created
+/// afterwards.
+#[tauri::command]
+pub fn set_default_webview_options(
+    app: tauri::AppHandle,
+    registry: tauri::State<'_, WebviewRegistry>,
+    options: DefaultWebviewOptions,
+) -> Result<(), WebviewError> {
+    info!(target: "webview", "Setting default webview options");
+    store::set_value(&app, DEFAULT_WEBVIEW_OPTIONS_KEY, &options)
+        .map_err(WebviewError::PlatformError)?;
+    *registry.default_options.lock() = Some(options);
+    Ok(())
+}
+
+/// Which settings `configure_child_webview` applied in place and which take effect
+/// only once the webview is recreated
+#[derive(Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ConfigureResult {
+    pub applied: Vec<&'static str>,
+    pub requires_recreate: Vec<&'static str>,
+}
+
+// URLs other than http(s) a webview with allowed hosts may still navigate to (the page
+// suspended webviews are parked on)
+const ALWAYS_ALLOWED_URLS: &[&str] = &[SUSPENDED_URL];
+
+/// Checks a URL against a list of allowed hosts (subdomains included); an empty list
+/// allows everything. With hosts set, only http(s) URLs on them and `about:blank` pass,
+/// so `file:`, `data:`, `javascript:` and other schemes can't get around the list.
+fn is_url_allowed(allowed: &[String], url: &tauri::Url) -> bool {
+    if allowed.is_empty() || ALWAYS_ALLOWED_URLS.contains(&url.as_str()) {
+        return true;
+    }
+    if !matches!(url.scheme(), "http" | "https") {
+        return false;
+    }
+    let host = url.host_str().unwrap_or_default().to_ascii_lowercase();
+    allowed.iter().any(|allowed| {
+        let allowed = allowed.to_ascii_lowercase();
+        host == allowed || host.ends_with(&format!(".{}", allowed))
+    })
+}
+
+/// Checks a navigation against a webview's allowed hosts
+fn is_navigation_allowed(app: &tauri::AppHandle, label: &str, url: &tauri::Url) -> bool {
+    let Some(allowed) = effective_config(app, label).allowed_hosts else {
+        return true;
+    };
+    let permitted = is_url_allowed(&allowed, url);
+    if !permitted {
+        warn!(target: "webview", "Blocked navigation of '{}' to {}", label, url);
+    }
+    permitted
+}
+
+/// Builds the script that (re)applies a webview's injected CSS and script to the current page
+fn injection_script(config: &ChildWebviewConfig) -> Option<String> {
+    let mut script = String::new();
+    if let Some(css) = &config.css {
+        let css = serde_json::to_string(css).ok()?;
+        script.push_str(&INJECT_CSS_SCRIPT.replace("__CSS__", &css));
+    }
+    if let Some(user_script) = &config.script {
+        script.push_str(user_script);
+    }
+    (!script.is_empty()).then_some(script)
+}
+
+/// Payload of the `webview-title-changed` event
+#[derive(Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WebviewTitleChanged {
+    pub label: String,
+    pub title: String,
+}
+
+/// Gets the window whose title follows a webview's page title: the one named in its
+/// `titleWindow` setting, otherwise the display window hosting it. The main control
+/// window is never retitled from embedded content.
+fn title_window(
+    app: &tauri::AppHandle,
+    webview: &tauri::Webview,
+    config: &ChildWebviewConfig,
+) -> Option<tauri::WebviewWindow> {
+    let label = match &config.title_window {
+        Some(label) => label.clone(),
+        None => webview.window().label().to_string(),
+    };
+    if label == MAIN_WINDOW_LABEL || !is_display_window(&label) {
+        return None;
+    }
+    app.get_webview_window(&label)
+}
+
+/// Emits `webview-title-changed` when a child webview's or display window's page changes
+/// its title (e.g. to "Now Playing: ..."), and applies it as the display window's title
+/// if the webview is configured to
+pub fn on_title_changed(webview: &tauri::Webview, title: String) {
+    let app = webview.app_handle();
+    let label = webview.label().to_string();
+    let config = effective_config(app, &label);
+    if config.sync_window_title.unwrap_or(false) {
+        match title_window(app, webview, &config) {
+            Some(window) => {
+                if let Err(e) = window.set_title(&title) {
+                    warn!(target: "webview", "Failed to set window title from '{}': {}", label, e);
+                }
+            }
+            None => warn!(
+                target: "webview",
+                "No display window to sync the title of '{}' to",
+                label
+            ),
+        }
+    }
+    let payload = WebviewTitleChanged { label, title };
+    if let Err(e) = app.emit("webview-title-changed", payload) {
+        warn!(target: "webview", "Failed to emit webview-title-changed: {}", e);
+    }
+}
+
+/// Applies a webview's injected CSS and script to the page it has loaded
+fn apply_injections(webview: &tauri::Webview) {
+    let script = injection_script(&effective_config(webview.app_handle(), webview.label()));
+    if let Some(script) = script {
+        if let Err(e) = webview.eval(&script) {
+            warn!(
+                target: "webview",
+                "Failed to inject into '{}': {}",
+                webview.label(),
+                e
+            );
+        }
+    }
+}
+
+/// Updates a child webview's settings without recreating it where the platform allows.
+/// The host allowlist, CSS and script apply to the current page right away and to every
+/// page loaded afterwards, and title sync from the next title change. The user agent can't
+/// be changed on a live webview: it is kept and used when the webview is next created, and
+/// reported in `requiresRecreate`.
+#[tauri::command]
+pub async fn configure_child_webview(
+    app: tauri::AppHandle,
+    registry: tauri::State<'_, WebviewRegistry>,
+    label: String,
+    options: ChildWebviewConfig,
+) -> Result<ConfigureResult, WebviewError> {
+    let webview = app
+        .get_webview(&label)
+        .ok_or_else(|| WebviewError::NotFound(label.clone()))?;
+    info!(target: "webview", "Configuring webview '{}'", label);
+
+    let mut result = ConfigureResult::default();
+    {
+        let mut live = registry.live_config.lock();
+        let config = live.entry(label.clone()).or_default();
+        if let Some(hosts) = options.allowed_hosts {
+            config.allowed_hosts = Some(hosts);
+            result.applied.push("allowedHosts");
+        }
+        if let Some(css) = options.css {
+            config.css = Some(css);
+            result.applied.push("css");
+        }
+        if let Some(script) = options.script {
+            config.script = Some(script);
+            result.applied.push("script");
+        }
+        if let Some(user_agent) = options.user_agent {
+            config.user_agent = Some(user_agent);
+            result.requires_recreate.push("userAgent");
+        }
+        if let Some(sync_window_title) = options.sync_window_title {
+            config.sync_window_title = Some(sync_window_title);
+            result.applied.push("syncWindowTitle");
+        }
+        if let Some(title_window) = options.title_window {
+            config.title_window = Some(title_window);
+            result.applied.push("titleWindow");
+        }
+    }
+
+    if result.applied.contains(&"css") || result.applied.contains(&"script") {
+        apply_injections(&webview);
+    }
+    Ok(result)
+}
+
+/// Creates a child webview, or repositions and shows it if it already exists
+/// (also navigating it to `url` when `navigate` is set)
+async fn ensure_child_webview(
+    app: &tauri::AppHandle,
+    label: &str,
+    url: &str,
+    position: LogicalPosition<f64>,
+    size: LogicalSize<f64>,
+    options: &ChildWebviewOptions,
+    navigate: bool,
+) -> Result<(), WebviewError> {
+    info!(target: "webview", "Creating child webview '{}'", label);
+    info!(target: "webview", "URL: {}", url);
+    info!(
+        target: "webview",
+        "Position: ({}, {}), Size: {}x{}",
+        position.x, position.y, size.width, size.height
     );
 
+    let parsed_url = parse_webview_url(url)?;
+
     // Get the main window with retry logic for timing issues
-    let main_window = get_main_window_with_retry(&app).await?;
+    let main_window = get_main_window_with_retry(app).await?;
+
+    // Serialize check-then-create per label so concurrent calls can't both create it;
+    // a second call waits for the first and then takes the update path below
+    let registry = app.state::<WebviewRegistry>();
+    let _creating = CreatingGuard::acquire(&registry, label).await?;
 
     // Check if webview already exists - if so, update position and show it
-    if let Some(existing) = app.get_webview(&label) {
-        println!("[webview] Webview '{}' already exists, updating position and showing it", label);
-        existing
-            .set_position(LogicalPosition::new(x, y))
-            .map_err(|e| format!("Failed to set position: {}", e))?;
-        existing
-            .set_size(LogicalSize::new(width, height))
-            .map_err(|e| format!("Failed to set size: {}", e))?;
-        existing
-            .show()
-            .map_err(|e| format!("Failed to show webview: {}", e))?;
-        return Ok(());
+    if let Some(existing) = app.get_webview(label) {
+        info!(
+            target: "webview",
+            "Webview '{}' already exists, updating position and showing it",
+            label
+        );
+        // A suspended webview is parked on a blank page; bring back its page first
+        if let Some(suspended) = registry.suspended.lock().remove(label) {
+            if !navigate {
+                info!(target: "webview", "Resuming suspended webview '{}'", label);
+                existing
+                    .navigate(suspended.url)
+                    .map_err(|e| WebviewError::platform("Failed to navigate webview", e))?;
+            }
+        }
+        if navigate && existing.url().ok().as_ref() != Some(&parsed_url) {
+            info!(target: "webview", "Navigating webview '{}' to {}", label, parsed_url);
+            existing
+                .navigate(parsed_url)
+                .map_err(|e| WebviewError::platform("Failed to navigate webview", e))?;
+        }
+        return if options.visible {
+            show_webview_at(&existing, position, size)
+        } else {
+            place_webview(&existing, position, size)
+        };
     }
 
-    // Create the webview URL
-    let webview_url = WebviewUrl::External(
-        url.parse()
-            .map_err(|e| format!("Invalid URL '{}': {}", url, e))?,
-    );
-
     // Build and add the child webview with modern Chrome user agent
     // Note: We don't use auto_resize() because we want to control the exact position
     // Disable background throttling to ensure smooth video playback (macOS 14.0+)
-    let webview_builder = WebviewBuilder::new(&label, webview_url)
-        .user_agent(CHROME_USER_AGENT)
-        .background_throttling(BackgroundThrottlingPolicy::Disabled);
+    // A user agent set with configure_child_webview or the default options replaces
+    // the built-in one
+    let user_agent = effective_config(app, label)
+        .user_agent
+        .unwrap_or_else(|| CHROME_USER_AGENT.to_string());
+    let navigation_app = app.clone();
+    let navigation_label = label.to_string();
+    let mut webview_builder = WebviewBuilder::new(label, WebviewUrl::External(parsed_url))
+        .user_agent(&user_agent)
+        .initialization_script(MESSAGE_BRIDGE_SCRIPT)
+        .background_throttling(BackgroundThrottlingPolicy::Disabled)
+        .on_navigation(move |url| is_navigation_allowed(&navigation_app, &navigation_label, url))
+        .on_page_load(|webview, payload| {
+            if payload.event() == PageLoadEvent::Finished {
+                apply_injections(&webview);
+                crate::webview_recovery::on_page_loaded(&webview, payload.url().clone());
+            }
+        })
+        .on_document_title_changed(|webview, title| on_title_changed(&webview, title));
+
+    // Both run after the message bridge, so the scripts can use window.churchHub
+    if let Some(script) = default_options(app).init_script {
+        webview_builder = webview_builder.initialization_script(&script);
+    }
+    if let Some(script) = &options.init_script {
+        webview_builder = webview_builder.initialization_script(script);
+    }
 
     // Get the window reference for add_child
     let window = main_window.as_ref().window();
 
-    window
-        .add_child(
-            webview_builder,
-            LogicalPosition::new(x, y),
-            LogicalSize::new(width, height),
-        )
-        .map_err(|e| format!("Failed to create child webview: {}", e))?;
+    if options.visible {
+        window
+            .add_child(webview_builder, position, size)
+            .map_err(|e| WebviewError::platform("Failed to create child webview", e))?;
+        // Drop any stale entry left by a webview of the same label closed elsewhere
+        registry.hidden.lock().remove(label);
+    } else {
+        // Create it outside the window, then hide it and move it into place, so no frame
+        // of the loading page is drawn
+        let webview = window
+            .add_child(
+                webview_builder,
+                LogicalPosition::new(-size.width, -size.height),
+                size,
+            )
+            .map_err(|e| WebviewError::platform("Failed to create child webview", e))?;
+        webview
+            .hide()
+            .map_err(|e| WebviewError::platform("Failed to hide webview", e))?;
+        registry.hidden.lock().insert(label.to_string());
+        place_webview(&webview, position, size)?;
+    }
 
-    println!("[webview] Child webview '{}' created successfully", label);
+    info!(
+        target: "webview",
+        "Child webview '{}' created successfully{}",
+        label,
+        if options.visible { "" } else { " (hidden)" }
+    );
 
     Ok(())
 }
@@ -129,58 +797,146 @@ pub async fn show_child_webview(
     y: f64,
     width: f64,
     height: f64,
-) -> Result<(), String> {
-    println!("[webview] Showing webview '{}' at ({}, {}) size {}x{}", label, x, y, width, height);
+) -> Result<(), WebviewError> {
+    info!(
+        target: "webview",
+        "Showing webview '{}' at ({}, {}) size {}x{}",
+        label,
+        x,
+        y,
+        width,
+        height
+    );
 
     let webview = app
         .get_webview(&label)
-        .ok_or_else(|| format!("Webview '{}' not found", label))?;
+        .ok_or_else(|| WebviewError::NotFound(label.clone()))?;
 
-    // Update position and size
-    webview
-        .set_position(LogicalPosition::new(x, y))
-        .map_err(|e| format!("Failed to set position: {}", e))?;
-    webview
-        .set_size(LogicalSize::new(width, height))
-        .map_err(|e| format!("Failed to set size: {}", e))?;
+    show_webview_at(
+        &webview,
+        LogicalPosition::new(x, y),
+        LogicalSize::new(width, height),
+    )?;
 
-    webview
-        .show()
-        .map_err(|e| format!("Failed to show webview: {}", e))?;
-
-    println!("[webview] Webview '{}' shown", label);
+    info!(target: "webview", "Webview '{}' shown", label);
     Ok(())
 }
 
 /// Hides a child webview (keeps it running in background)
 #[tauri::command]
-pub async fn hide_child_webview(app: tauri::AppHandle, label: String) -> Result<(), String> {
-    println!("[webview] Hiding webview '{}'", label);
+pub async fn hide_child_webview(
+    app: tauri::AppHandle,
+    registry: tauri::State<'_, WebviewRegistry>,
+    label: String,
+) -> Result<(), WebviewError> {
+    info!(target: "webview", "Hiding webview '{}'", label);
 
     if let Some(webview) = app.get_webview(&label) {
         webview
             .hide()
-            .map_err(|e| format!("Failed to hide webview: {}", e))?;
-        println!("[webview] Webview '{}' hidden", label);
+            .map_err(|e| WebviewError::platform("Failed to hide webview", e))?;
+        registry.hidden.lock().insert(label.clone());
+        info!(target: "webview", "Webview '{}' hidden", label);
     } else {
-        println!("[webview] Webview '{}' not found (already closed?)", label);
+        info!(target: "webview", "Webview '{}' not found (already closed?)", label);
     }
 
     Ok(())
 }
 
+/// Suspends a child webview to save CPU/GPU: it is hidden and unloaded (parked on
+/// `about:blank`), stopping its media and timers. Unlike `hide_child_webview`, which keeps
+/// the page running, the page state is lost and reloads on `resume_child_webview`.
+#[tauri::command]
+pub async fn suspend_child_webview(
+    app: tauri::AppHandle,
+    registry: tauri::State<'_, WebviewRegistry>,
+    label: String,
+) -> Result<(), WebviewError> {
+    let webview = app
+        .get_webview(&label)
+        .ok_or_else(|| WebviewError::NotFound(label.clone()))?;
+    if registry.suspended.lock().contains_key(&label) {
+        return Ok(());
+    }
+    info!(target: "webview", "Suspending webview '{}'", label);
+
+    let url = webview
+        .url()
+        .map_err(|e| WebviewError::platform("Failed to get webview URL", e))?;
+    let hidden = registry.is_hidden(&label);
+
+    webview
+        .hide()
+        .map_err(|e| WebviewError::platform("Failed to hide webview", e))?;
+    registry.hidden.lock().insert(label.clone());
+    webview
+        .navigate(parse_webview_url(SUSPENDED_URL)?)
+        .map_err(|e| WebviewError::platform("Failed to unload webview", e))?;
+
+    registry
+        .suspended
+        .lock()
+        .insert(label, SuspendedWebview { url, hidden });
+    Ok(())
+}
+
+/// Reloads a suspended child webview's page and shows it again if it was visible when
+/// suspended. Returns false if the webview wasn't suspended.
+#[tauri::command]
+pub async fn resume_child_webview(
+    app: tauri::AppHandle,
+    registry: tauri::State<'_, WebviewRegistry>,
+    label: String,
+) -> Result<bool, WebviewError> {
+    let webview = app
+        .get_webview(&label)
+        .ok_or_else(|| WebviewError::NotFound(label.clone()))?;
+    let Some(suspended) = registry.suspended.lock().remove(&label) else {
+        return Ok(false);
+    };
+    info!(target: "webview", "Resuming webview '{}' at {}", label, suspended.url);
+
+    webview
+        .navigate(suspended.url)
+        .map_err(|e| WebviewError::platform("Failed to navigate webview", e))?;
+    if !suspended.hidden {
+        webview
+            .show()
+            .map_err(|e| WebviewError::platform("Failed to show webview", e))?;
+        registry.hidden.lock().remove(&label);
+    }
+    Ok(true)
+}
+
+/// Checks if a child webview is suspended
+#[tauri::command]
+pub fn is_child_webview_suspended(
+    registry: tauri::State<'_, WebviewRegistry>,
+    label: String,
+) -> bool {
+    registry.suspended.lock().contains_key(&label)
+}
+
 /// Closes a child webview by label (destroys it)
 #[tauri::command]
-pub async fn close_child_webview(app: tauri::AppHandle, label: String) -> Result<(), String> {
-    println!("[webview] Closing webview '{}'", label);
+pub async fn close_child_webview(
+    app: tauri::AppHandle,
+    registry: tauri::State<'_, WebviewRegistry>,
+    label: String,
+) -> Result<(), WebviewError> {
+    info!(target: "webview", "Closing webview '{}'", label);
 
     if let Some(webview) = app.get_webview(&label) {
         webview
             .close()
-            .map_err(|e| format!("Failed to close webview '{}': {}", label, e))?;
-        println!("[webview] Webview '{}' closed", label);
+            .map_err(|e| {
+                WebviewError::platform(&format!("Failed to close webview '{}'", label), e)
+            })?;
+        registry.forget(&label);
+        info!(target: "webview", "Webview '{}' closed", label);
     } else {
-        println!("[webview] Webview '{}' not found (already closed?)", label);
+        info!(target: "webview", "Webview '{}' not found (already closed?)", label);
     }
 
     Ok(())
@@ -188,31 +944,136 @@ pub async fn close_child_webview(app: tauri::AppHandle, label: String) -> Result
 
 /// Checks if a webview exists
 #[tauri::command]
-pub async fn webview_exists(app: tauri::AppHandle, label: String) -> Result<bool, String> {
+pub async fn webview_exists(
+    app: tauri::AppHandle,
+    label: String,
+) -> Result<bool, WebviewError> {
     Ok(app.get_webview(&label).is_some())
 }
 
 /// Repositions and resizes a child webview
+/// Rapid updates (e.g. while dragging a display region) are coalesced so only the latest
+/// one in each window is applied; the last update is always applied.
 #[tauri::command]
 pub async fn update_child_webview(
     app: tauri::AppHandle,
+    registry: tauri::State<'_, WebviewRegistry>,
     label: String,
     x: f64,
     y: f64,
     width: f64,
     height: f64,
-) -> Result<(), String> {
+) -> Result<(), WebviewError> {
+    if app.get_webview(&label).is_none() {
+        return Err(WebviewError::NotFound(label));
+    }
+
+    let bounds = (LogicalPosition::new(x, y), LogicalSize::new(width, height));
+    // An existing entry means a flush is already scheduled and will pick up these bounds
+    if registry
+        .pending_bounds
+        .lock()
+        .insert(label.clone(), bounds)
+        .is_some()
+    {
+        return Ok(());
+    }
+
+    tauri::async_runtime::spawn(async move {
+        sleep(UPDATE_COALESCE_WINDOW).await;
+        let Some((position, size)) = app
+            .state::<WebviewRegistry>()
+            .pending_bounds
+            .lock()
+            .remove(&label)
+        else {
+            return;
+        };
+        let Some(webview) = app.get_webview(&label) else {
+            return;
+        };
+        if let Err(e) = webview
+            .set_position(position)
+            .and_then(|_| webview.set_size(size))
+        {
+            warn!(target: "webview", "Failed to update webview '{}': {}", label, e);
+        }
+    });
+
+    Ok(())
+}
+
+/// Message sent by a child webview, emitted to the frontend as `webview-message`
+#[derive(Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WebviewMessage {
+    pub label: String,
+    pub message: Value,
+}
+
+/// Sends a JSON message into a child webview as a `church-hub-message` DOM event
+/// (the message is available as `event.detail`)
+#[tauri::command]
+pub async fn webview_post_message(
+    app: tauri::AppHandle,
+    label: String,
+    message: Value,
+) -> Result<(), WebviewError> {
     let webview = app
         .get_webview(&label)
-        .ok_or_else(|| format!("Webview '{}' not found", label))?;
+        .ok_or_else(|| WebviewError::NotFound(label.clone()))?;
 
+    // Serialized JSON is a valid JS expression, so it can be embedded directly
+    let script = format!(
+        "window.dispatchEvent(new CustomEvent({:?}, {{ detail: {} }}));",
+        MESSAGE_EVENT, message
+    );
     webview
-        .set_position(LogicalPosition::new(x, y))
-        .map_err(|e| format!("Failed to set position: {}", e))?;
+        .eval(&script)
+        .map_err(|e| WebviewError::platform("Failed to post message", e))
+}
 
-    webview
-        .set_size(LogicalSize::new(width, height))
-        .map_err(|e| format!("Failed to set size: {}", e))?;
+/// Receives a message from a child webview and re-emits it as a `webview-message` event
+#[tauri::command]
+pub async fn webview_send_message(
+    app: tauri::AppHandle,
+    webview: tauri::Webview,
+    message: Value,
+) -> Result<(), WebviewError> {
+    app.emit(
+        "webview-message",
+        WebviewMessage {
+            label: webview.label().to_string(),
+            message,
+        },
+    )
+    .map_err(|e| WebviewError::platform("Failed to emit webview message", e))
+}
 
-    Ok(())
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn allowed(url: &str) -> bool {
+        is_url_allowed(&["example.com".to_string()], &url.parse().unwrap())
+    }
+
+    #[test]
+    fn allowlist_limits_hosts() {
+        assert!(allowed("https://example.com/slides"));
+        assert!(allowed("https://cdn.example.com/video"));
+        assert!(!allowed("https://example.org/"));
+        assert!(!allowed("https://notexample.com/"));
+        assert!(allowed("about:blank"));
+        assert!(is_url_allowed(&[], &"file:///etc/passwd".parse().unwrap()));
+    }
+
+    #[test]
+    fn allowlist_rejects_other_schemes() {
+        assert!(!allowed("file:///etc/passwd"));
+        assert!(!allowed("data:text/html,<script>alert(1)</script>"));
+        assert!(!allowed("javascript:alert(1)"));
+        assert!(!allowed("blob:https://example.com/1234"));
+        assert!(!allowed("churchhub://import"));
+    }
 }
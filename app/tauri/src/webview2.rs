@@ -0,0 +1,160 @@
+use crate::store;
+use log::info;
+#[cfg(target_os = "windows")]
+use std::path::{Path, PathBuf};
+use tauri::AppHandle;
+
+/// GPU flags passed to WebView2 for hardware-accelerated video playback (e.g. YouTube)
+#[cfg(target_os = "windows")]
+const GPU_BROWSER_ARGUMENTS: &[&str] = &[
+    "--ignore-gpu-blocklist",
+    "--enable-gpu-rasterization",
+    "--enable-accelerated-video-decode",
+];
+
+/// Store key for the hardware acceleration preference
+pub const HARDWARE_ACCELERATION_KEY: &str = "hardwareAcceleration";
+
+/// Store key for extra WebView2 arguments (e.g. proxy or certificate flags)
+pub const BROWSER_ARGUMENTS_KEY: &str = "webview2Arguments";
+
+/// Extra WebView2 arguments from the environment, space separated
+#[cfg(target_os = "windows")]
+const BROWSER_ARGUMENTS_ENV: &str = "CHURCH_HUB_WEBVIEW2_ARGS";
+
+/// Environment variable read by WebView2
+#[cfg(target_os = "windows")]
+const WEBVIEW2_ARGUMENTS_ENV: &str = "WEBVIEW2_ADDITIONAL_BROWSER_ARGUMENTS";
+
+/// Overrides the stored preference (`0`/`false`/`off` or `1`/`true`/`on`)
+#[cfg(target_os = "windows")]
+const HARDWARE_ACCELERATION_ENV: &str = "CHURCH_HUB_HARDWARE_ACCELERATION";
+
+/// Parses an on/off environment value
+#[cfg(target_os = "windows")]
+fn parse_switch(value: &str) -> Option<bool> {
+    match value.trim().to_ascii_lowercase().as_str() {
+        "1" | "true" | "on" | "yes" => Some(true),
+        "0" | "false" | "off" | "no" => Some(false),
+        _ => None,
+    }
+}
+
+/// Path of the store file, resolved before the app is built (same as `app_data_dir`)
+#[cfg(target_os = "windows")]
+fn store_path_before_build(identifier: &str) -> Option<PathBuf> {
+    std::env::var_os("APPDATA")
+        .map(|dir| PathBuf::from(dir).join(identifier).join(store::STORE_FILE))
+}
+
+/// Decides whether to apply the GPU flags: env var first, then the stored preference (default on)
+#[cfg(target_os = "windows")]
+fn hardware_acceleration_enabled(store_file: Option<&Path>) -> bool {
+    if let Some(enabled) = std::env::var(HARDWARE_ACCELERATION_ENV)
+        .ok()
+        .and_then(|value| parse_switch(&value))
+    {
+        info!(
+            target: "webview2",
+            "Hardware acceleration from {HARDWARE_ACCELERATION_ENV}: {enabled}"
+        );
+        return enabled;
+    }
+    store_file
+        .and_then(|path| store::get_value_at(path, HARDWARE_ACCELERATION_KEY))
+        .unwrap_or(true)
+}
+
+/// Validates browser arguments, which must be `--flag` or `--flag=value`
+fn validate_arguments(arguments: &[String]) -> Result<(), String> {
+    match arguments
+        .iter()
+        .find(|arg| !arg.starts_with("--") || arg.len() == 2 || arg.contains(char::is_whitespace))
+    {
+        Some(invalid) => Err(format!("Invalid browser argument '{invalid}'")),
+        None => Ok(()),
+    }
+}
+
+/// Merges argument lists in order, keeping one entry per flag (later values win)
+/// and dropping invalid entries
+#[cfg(target_os = "windows")]
+fn merge_arguments<'a>(sources: impl IntoIterator<Item = &'a str>) -> Vec<String> {
+    let mut merged: Vec<String> = Vec::new();
+    for arg in sources {
+        if validate_arguments(&[arg.to_string()]).is_err() {
+            info!(target: "webview2", "Ignoring invalid browser argument {arg:?}");
+            continue;
+        }
+        let flag = arg.split('=').next().unwrap_or(arg);
+        merged.retain(|existing| existing.split('=').next() != Some(flag));
+        merged.push(arg.to_string());
+    }
+    merged
+}
+
+/// Sets `WEBVIEW2_ADDITIONAL_BROWSER_ARGUMENTS` before any webview is created, merging
+/// (in increasing precedence) any value already in the environment, the GPU flags,
+/// the stored extra arguments and `CHURCH_HUB_WEBVIEW2_ARGS`
+#[cfg(target_os = "windows")]
+pub fn apply_browser_arguments(identifier: &str) {
+    let store_file = store_path_before_build(identifier);
+    let inherited = std::env::var(WEBVIEW2_ARGUMENTS_ENV).unwrap_or_default();
+    let gpu: &[&str] = if hardware_acceleration_enabled(store_file.as_deref()) {
+        GPU_BROWSER_ARGUMENTS
+    } else {
+        &[]
+    };
+    let stored: Vec<String> = store_file
+        .as_deref()
+        .and_then(|path| store::get_value_at(path, BROWSER_ARGUMENTS_KEY))
+        .unwrap_or_default();
+    let from_env = std::env::var(BROWSER_ARGUMENTS_ENV).unwrap_or_default();
+
+    let arguments = merge_arguments(
+        inherited
+            .split_whitespace()
+            .chain(gpu.iter().copied())
+            .chain(stored.iter().map(String::as_str))
+            .chain(from_env.split_whitespace()),
+    )
+    .join(" ");
+
+    info!(target: "webview2", "Additional browser arguments: {arguments:?}");
+    if arguments.is_empty() {
+        std::env::remove_var(WEBVIEW2_ARGUMENTS_ENV);
+    } else {
+        std::env::set_var(WEBVIEW2_ARGUMENTS_ENV, arguments);
+    }
+}
+
+/// Gets the stored hardware acceleration preference (WebView2 GPU flags, Windows only)
+#[tauri::command]
+pub fn get_hardware_acceleration(app: AppHandle) -> bool {
+    store::get_value(&app, HARDWARE_ACCELERATION_KEY).unwrap_or(true)
+}
+
+/// Stores the hardware acceleration preference, applied on the next launch
+/// (`CHURCH_HUB_HARDWARE_ACCELERATION` still takes precedence)
+#[tauri::command]
+pub fn set_hardware_acceleration(app: AppHandle, enabled: bool) -> Result<(), String> {
+    info!(target: "webview2", "Setting hardware acceleration={enabled} (applies after restart)");
+    store::set_value(&app, HARDWARE_ACCELERATION_KEY, &enabled)
+}
+
+/// Gets the stored extra WebView2 arguments
+#[tauri::command]
+pub fn get_webview2_arguments(app: AppHandle) -> Vec<String> {
+    store::get_value(&app, BROWSER_ARGUMENTS_KEY).unwrap_or_default()
+}
+
+/// Stores extra WebView2 arguments (Windows only), merged with the GPU flags on the next launch
+#[tauri::command]
+pub fn set_webview2_arguments(app: AppHandle, arguments: Vec<String>) -> Result<(), String> {
+    validate_arguments(&arguments)?;
+    info!(
+        target: "webview2",
+        "Setting extra browser arguments {arguments:?} (applies after restart)"
+    );
+    store::set_value(&app, BROWSER_ARGUMENTS_KEY, &arguments)
+}
@@ -0,0 +1,164 @@
+use crate::error::WebviewError;
+use log::{info, warn};
+use parking_lot::Mutex;
+use serde::Serialize;
+use std::collections::BTreeSet;
+use std::net::{TcpStream, ToSocketAddrs};
+use std::time::Duration;
+use tauri::{AppHandle, Emitter, Manager};
+
+/// How often the hosts of failed auto-reload webviews are probed
+const PROBE_INTERVAL: Duration = Duration::from_secs(5);
+
+/// How long a single connection attempt may take
+const PROBE_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// State for reloading child webviews whose page failed to load
+#[derive(Default)]
+pub struct WebviewRecoveryState {
+    /// Webviews opted into auto-reload
+    pub auto_reload: Mutex<BTreeSet<String>>,
+    /// Auto-reload webviews whose last page load failed
+    pub failed: Mutex<BTreeSet<String>>,
+}
+
+/// Payload of the `webview-reloaded` event
+#[derive(Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WebviewReloaded {
+    pub label: String,
+    pub url: String,
+}
+
+/// Checks whether a TCP connection can be opened to the host serving a URL (blocking).
+/// Returns None for URLs without a network host (e.g. `about:blank`).
+fn is_host_reachable(url: &tauri::Url) -> Option<bool> {
+    if !matches!(url.scheme(), "http" | "https") {
+        return None;
+    }
+    let host = url.host_str()?;
+    let port = url.port_or_known_default()?;
+    let Ok(addresses) = (host, port).to_socket_addrs() else {
+        // DNS failing is the usual symptom of the network being down
+        return Some(false);
+    };
+    Some(
+        addresses
+            .into_iter()
+            .any(|address| TcpStream::connect_timeout(&address, PROBE_TIMEOUT).is_ok()),
+    )
+}
+
+/// Records whether a page load of an auto-reload webview failed. The platform webviews
+/// don't report load errors, so a page that finished loading while its host was
+/// unreachable is taken to be the browser's error page.
+pub fn on_page_loaded(webview: &tauri::Webview, url: tauri::Url) {
+    let app = webview.app_handle().clone();
+    let label = webview.label().to_string();
+    let Some(state) = app.try_state::<WebviewRecoveryState>() else {
+        return;
+    };
+    if !state.auto_reload.lock().contains(&label) {
+        return;
+    }
+
+    tauri::async_runtime::spawn(async move {
+        let probe_url = url.clone();
+        let reachable = tokio::task::spawn_blocking(move || is_host_reachable(&probe_url))
+            .await
+            .ok()
+            .flatten();
+        let state = app.state::<WebviewRecoveryState>();
+        if reachable == Some(false) && state.auto_reload.lock().contains(&label) {
+            warn!(target: "webview", "Page load of '{}' failed ({})", label, url);
+            state.failed.lock().insert(label);
+        } else {
+            state.failed.lock().remove(&label);
+        }
+    });
+}
+
+/// Enables or disables reloading a child webview whose page failed to load once its
+/// host is reachable again (e.g. the venue Wi-Fi dropped while it was loading)
+#[tauri::command]
+pub async fn set_webview_auto_reload(
+    app: AppHandle,
+    state: tauri::State<'_, WebviewRecoveryState>,
+    label: String,
+    enabled: bool,
+) -> Result<(), WebviewError> {
+    if app.get_webview(&label).is_none() {
+        return Err(WebviewError::NotFound(label));
+    }
+    info!(target: "webview", "Setting auto-reload={} on '{}'", enabled, label);
+
+    if enabled {
+        state.auto_reload.lock().insert(label);
+    } else {
+        state.auto_reload.lock().remove(&label);
+        state.failed.lock().remove(&label);
+    }
+    Ok(())
+}
+
+/// Checks if a child webview reloads automatically on network recovery
+#[tauri::command]
+pub fn get_webview_auto_reload(
+    state: tauri::State<'_, WebviewRecoveryState>,
+    label: String,
+) -> bool {
+    state.auto_reload.lock().contains(&label)
+}
+
+/// Probes the host of each auto-reload webview whose page failed to load, and reloads
+/// the webview once its host is reachable again, emitting `webview-reloaded`. Closed
+/// webviews are dropped.
+pub fn watch_network_recovery(app: &AppHandle) {
+    let handle = app.clone();
+    tauri::async_runtime::spawn(async move {
+        loop {
+            tokio::time::sleep(PROBE_INTERVAL).await;
+
+            let state = handle.state::<WebviewRecoveryState>();
+            let labels: Vec<String> = state.failed.lock().iter().cloned().collect();
+            for label in labels {
+                let Some(webview) = handle.get_webview(&label) else {
+                    state.auto_reload.lock().remove(&label);
+                    state.failed.lock().remove(&label);
+                    continue;
+                };
+                let Ok(url) = webview.url() else {
+                    continue;
+                };
+
+                let probe_url = url.clone();
+                let Ok(Some(true)) =
+                    tokio::task::spawn_blocking(move || is_host_reachable(&probe_url)).await
+                else {
+                    continue;
+                };
+                // Skip webviews that were opted out or reloaded while probing
+                if !state.failed.lock().remove(&label) {
+                    continue;
+                }
+
+                info!(
+                    target: "webview",
+                    "Network recovered, reloading '{}' ({})",
+                    label, url
+                );
+                if let Err(e) = webview.reload() {
+                    warn!(target: "webview", "Failed to reload '{}': {}", label, e);
+                    continue;
+                }
+                let payload = WebviewReloaded {
+                    label,
+                    url: url.to_string(),
+                };
+                if let Err(e) = handle.emit("webview-reloaded", payload) {
+                    warn!(target: "webview", "Failed to emit webview-reloaded: {}", e);
+                }
+            }
+        }
+    });
+}
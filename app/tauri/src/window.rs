@@ -0,0 +1,229 @@
+use crate::display::{self, ControlRoleLook, DisplayModeState};
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Emitter, Manager, WebviewWindow};
+use tauri_plugin_window_state::{AppHandleExt, StateFlags};
+
+/// Label of the main control window
+pub const MAIN_WINDOW_LABEL: &str = "main";
+
+/// What a window is used for
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum WindowRole {
+    /// Regular decorated window showing the control UI
+    Control,
+    /// Fullscreen, undecorated, always-on-top output (presenter mode on a single screen)
+    Display,
+}
+
+/// Payload of the `window-role-changed` event
+#[derive(Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WindowRoleChanged {
+    pub label: String,
+    pub role: WindowRole,
+}
+
+/// Gets a window by label, defaulting to the main window
+pub fn get_window(app: &AppHandle, label: Option<&str>) -> Result<WebviewWindow, String> {
+    let label = label.unwrap_or(MAIN_WINDOW_LABEL);
+    app.get_webview_window(label)
+        .ok_or_else(|| format!("Window '{}' not found", label))
+}
+
+/// Keeps a window above all others (defaults to the main window)
+#[tauri::command]
+pub async fn set_always_on_top(
+    app: AppHandle,
+    label: Option<String>,
+    enabled: bool,
+) -> Result<bool, String> {
+    let window = get_window(&app, label.as_deref())?;
+    info!(
+        target: "window",
+        "Setting always-on-top={} on '{}'",
+        enabled,
+        window.label()
+    );
+
+    window
+        .set_always_on_top(enabled)
+        .map_err(|e| format!("Failed to set always on top: {}", e))?;
+
+    window
+        .is_always_on_top()
+        .map_err(|e| format!("Failed to get always on top: {}", e))
+}
+
+/// Checks if a window is kept above all others (defaults to the main window)
+#[tauri::command]
+pub async fn get_always_on_top(app: AppHandle, label: Option<String>) -> Result<bool, String> {
+    get_window(&app, label.as_deref())?
+        .is_always_on_top()
+        .map_err(|e| format!("Failed to get always on top: {}", e))
+}
+
+/// Sets a window's title, shown in the OS taskbar (defaults to the main window)
+#[tauri::command]
+pub async fn set_window_title(
+    app: AppHandle,
+    label: Option<String>,
+    title: String,
+) -> Result<(), String> {
+    let window = get_window(&app, label.as_deref())?;
+    info!(
+        target: "window",
+        "Setting title of '{}' to '{}'",
+        window.label(),
+        title
+    );
+
+    window
+        .set_title(&title)
+        .map_err(|e| format!("Failed to set window title: {}", e))
+}
+
+/// Gets a window's title (defaults to the main window)
+#[tauri::command]
+pub async fn get_window_title(app: AppHandle, label: Option<String>) -> Result<String, String> {
+    get_window(&app, label.as_deref())?
+        .title()
+        .map_err(|e| format!("Failed to get window title: {}", e))
+}
+
+/// Shows or hides a window's title bar and borders (defaults to the main window).
+/// The window state plugin restores decorations on launch, so the change is saved
+/// right away instead of only when the window closes.
+#[tauri::command]
+pub async fn set_window_decorations(
+    app: AppHandle,
+    label: Option<String>,
+    enabled: bool,
+) -> Result<(), String> {
+    let window = get_window(&app, label.as_deref())?;
+    info!(
+        target: "window",
+        "Setting decorations={} on '{}'",
+        enabled,
+        window.label()
+    );
+
+    window
+        .set_decorations(enabled)
+        .map_err(|e| format!("Failed to set window decorations: {}", e))?;
+
+    if let Err(e) = app.save_window_state(StateFlags::DECORATIONS) {
+        warn!(target: "window", "Failed to save window state: {}", e);
+    }
+    Ok(())
+}
+
+/// Checks if a window shows its title bar and borders (defaults to the main window)
+#[tauri::command]
+pub async fn get_window_decorations(app: AppHandle, label: Option<String>) -> Result<bool, String> {
+    get_window(&app, label.as_deref())?
+        .is_decorated()
+        .map_err(|e| format!("Failed to get window decorations: {}", e))
+}
+
+/// Gets the role of a window by label
+fn window_role(state: &DisplayModeState, label: &str) -> WindowRole {
+    if state.display_roles.lock().contains_key(label) {
+        WindowRole::Display
+    } else {
+        WindowRole::Control
+    }
+}
+
+/// Puts a window back in the control role: out of fullscreen at its previous geometry,
+/// decorated and with its previous always-on-top setting. Also works on a window left
+/// fullscreen and undecorated by a previous session, which has no remembered look.
+fn restore_control_look(
+    window: &WebviewWindow,
+    state: &DisplayModeState,
+    look: ControlRoleLook,
+) -> Result<(), String> {
+    window
+        .set_always_on_top(look.always_on_top)
+        .map_err(|e| format!("Failed to set always on top: {}", e))?;
+    display::exit_fullscreen(window, state)?;
+    window
+        .set_decorations(look.decorated)
+        .map_err(|e| format!("Failed to set decorations: {}", e))
+}
+
+/// Switches a window between the control and display roles (defaults to the main window).
+/// The display role makes it fullscreen, undecorated and always on top; switching back
+/// restores its previous geometry and decorations, and always leaves fullscreen. Emits
+/// `window-role-changed` so the frontend can render the matching UI.
+#[tauri::command]
+pub async fn set_window_role(
+    app: AppHandle,
+    state: tauri::State<'_, DisplayModeState>,
+    label: Option<String>,
+    role: WindowRole,
+) -> Result<(), String> {
+    let window = get_window(&app, label.as_deref())?;
+    let label = window.label().to_string();
+    if role == WindowRole::Display && window_role(&state, &label) == role {
+        return Ok(());
+    }
+    info!(target: "window", "Setting role of '{}' to {:?}", label, role);
+
+    match role {
+        WindowRole::Display => {
+            let look = ControlRoleLook {
+                always_on_top: window.is_always_on_top().unwrap_or(false),
+                decorated: window.is_decorated().unwrap_or(true),
+            };
+            display::enter_fullscreen(&window, &state)?;
+            window
+                .set_decorations(false)
+                .map_err(|e| format!("Failed to set decorations: {}", e))?;
+            window
+                .set_always_on_top(true)
+                .map_err(|e| format!("Failed to set always on top: {}", e))?;
+            state.display_roles.lock().insert(label.clone(), look);
+        }
+        WindowRole::Control => {
+            let look = state
+                .display_roles
+                .lock()
+                .remove(&label)
+                .unwrap_or_default();
+            restore_control_look(&window, &state, look)?;
+        }
+    }
+
+    if let Err(e) = app.emit("window-role-changed", WindowRoleChanged { label, role }) {
+        warn!(target: "window", "Failed to emit window-role-changed: {}", e);
+    }
+    Ok(())
+}
+
+/// Switches every display-role window back to the control role, so the window state
+/// saved on exit isn't a fullscreen, undecorated window. Call when the app is exiting.
+pub fn restore_control_roles(app: &AppHandle) {
+    let Some(state) = app.try_state::<DisplayModeState>() else {
+        return;
+    };
+    let roles: Vec<_> = state.display_roles.lock().drain().collect();
+    for (label, look) in roles {
+        let Some(window) = app.get_webview_window(&label) else {
+            continue;
+        };
+        if let Err(e) = restore_control_look(&window, &state, look) {
+            warn!(target: "window", "Failed to restore '{}' on exit: {}", label, e);
+        }
+    }
+}
+
+/// Gets the role of a window (label defaults to the main window)
+#[tauri::command]
+pub fn get_window_role(
+    state: tauri::State<'_, DisplayModeState>,
+    label: Option<String>,
+) -> WindowRole {
+    window_role(&state, label.as_deref().unwrap_or(MAIN_WINDOW_LABEL))
+}